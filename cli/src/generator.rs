@@ -0,0 +1,141 @@
+//! Prisma generator mode.
+//!
+//! `prisma generate` runs the `provider` of a `generator` block, and sends it JSON-RPC requests on stdin,
+//! one per line. The responses are written to stderr, one per line.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use prisma_rust_schema_core::{ConfigValue, Generator, ImportOptions};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// The name of the schema source in error messages, when Prisma does not send the schema path
+const DEFAULT_SCHEMA_NAME: &str = "schema.prisma";
+
+#[derive(Deserialize)]
+struct Request {
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// The `generate` parameters used. Prisma also sends the DMMF, datasources and other generators
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeneratorOptions {
+    generator: GeneratorConfig,
+    schema_path: Option<String>,
+    datamodel: String,
+}
+
+#[derive(Deserialize)]
+struct GeneratorConfig {
+    output: Option<EnvValue>,
+    #[serde(default)]
+    config: HashMap<String, JsonConfigValue>,
+}
+
+/// A value which can be read from an environment variable, e.g. `output = env("OUTPUT")`
+#[derive(Deserialize)]
+struct EnvValue {
+    value: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonConfigValue {
+    String(String),
+    List(Vec<String>),
+}
+
+impl From<JsonConfigValue> for ConfigValue {
+    fn from(value: JsonConfigValue) -> Self {
+        match value {
+            JsonConfigValue::String(s) => ConfigValue::String(s),
+            JsonConfigValue::List(list) => ConfigValue::List(list),
+        }
+    }
+}
+
+/// Handles requests until stdin is closed
+pub fn run() -> Result<(), String> {
+    let stdin = std::io::stdin();
+    let mut stderr = std::io::stderr();
+
+    for line in stdin.lock().lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let request: Request = serde_json::from_str(&line)
+            .map_err(|e| format!("Unable to parse generator request: {e}"))?;
+
+        let response = match handle_request(&request.method, request.params) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": request.id, "result": result }),
+            Err(message) => json!({
+                "jsonrpc": "2.0",
+                "id": request.id,
+                "error": { "code": -32000, "message": message, "data": null },
+            }),
+        };
+
+        writeln!(stderr, "{response}").map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+fn handle_request(method: &str, params: Value) -> Result<Value, String> {
+    match method {
+        "getManifest" => Ok(json!({
+            "manifest": {
+                "prettyName": "Prisma Rust Schema",
+                "defaultOutput": "../src/models.rs",
+                "requiresEngines": [],
+            }
+        })),
+        "generate" => {
+            let options: GeneratorOptions = serde_json::from_value(params)
+                .map_err(|e| format!("Unable to parse generator options: {e}"))?;
+            generate(options)?;
+            Ok(Value::Null)
+        }
+        _ => Err(format!("Unknown method: {method}")),
+    }
+}
+
+fn generate(options: GeneratorOptions) -> Result<(), String> {
+    let out = options
+        .generator
+        .output
+        .and_then(|output| output.value)
+        .map(PathBuf::from)
+        .ok_or("The generator has no `output`")?;
+
+    let import_options = ImportOptions::from_config(
+        options
+            .generator
+            .config
+            .into_iter()
+            .map(|(key, value)| (key, value.into())),
+    )?;
+
+    let name = options
+        .schema_path
+        .unwrap_or_else(|| DEFAULT_SCHEMA_NAME.to_string());
+    let dir = std::env::current_dir().map_err(|e| e.to_string())?;
+    let generated = Generator::new(import_options)
+        .add_source(name, options.datamodel)
+        .base_dir(dir)
+        .generate_output()
+        .map_err(|e| e.to_string())?;
+    // Lines which are not JSON-RPC responses are not parsed by Prisma
+    crate::print_warnings(&generated.warnings);
+    let code = generated.to_source().map_err(|e| e.to_string())?;
+
+    crate::write(&out, &code)
+}
@@ -0,0 +1,180 @@
+//! # Prisma Rust Schema CLI
+//!
+//! Generates Rust sources from Prisma schema files, so the generated types can be committed and reviewed.
+//! Without a command, it runs as a Prisma generator.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use prisma_rust_schema_core::{Generator, ImportOptions};
+
+mod generator;
+
+/// The first line of every generated file
+const HEADER: &str = "// @generated by prisma-rust-schema. Do not edit.\n\n";
+
+#[derive(Parser)]
+#[command(name = "prisma-rust-schema", version, about)]
+struct Cli {
+    /// Without a command, JSON-RPC requests from `prisma generate` are handled on stdin
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generates Rust types from Prisma schema files
+    Generate(GenerateArgs),
+}
+
+/// The import options of `import_types!`, as arguments
+#[derive(Args)]
+struct GenerateArgs {
+    /// A schema file, directory, glob or URL. Can be repeated
+    #[arg(long, required = true)]
+    schema: Vec<String>,
+    /// The file to write. Defaults to stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+    /// Exits with an error if `--out` is not up to date, instead of writing it
+    #[arg(long, requires = "out")]
+    check: bool,
+    /// The prefix of every generated type name
+    #[arg(long)]
+    prefix: Option<String>,
+    /// The derive macros of every generated type, e.g. `Debug,Clone,serde::Serialize`
+    #[arg(long, value_delimiter = ',')]
+    derive: Option<Vec<String>>,
+    /// The models, enums and composite types to generate. Defaults to all
+    #[arg(long, value_delimiter = ',')]
+    include: Option<Vec<String>>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    relations: Option<bool>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    create_input: Option<bool>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    update_input: Option<bool>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    where_input: Option<bool>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    field_enums: Option<bool>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    unique_keys: Option<bool>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    id_newtypes: Option<bool>,
+    /// The expected SHA-256 of the remote schema, as hex
+    #[arg(long)]
+    sha256: Option<String>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    offline: Option<bool>,
+    /// Whether invalid `@prs.` annotations are errors. Defaults to `true`
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    strict: Option<bool>,
+    /// `chrono | time | jiff | bson | string`
+    #[arg(long)]
+    datetime: Option<String>,
+    /// `bson | string`
+    #[arg(long)]
+    object_id: Option<String>,
+    /// `serde_json` or the path to a custom type
+    #[arg(long)]
+    json: Option<String>,
+    /// `rust_decimal | bigdecimal | string`
+    #[arg(long)]
+    decimal: Option<String>,
+    /// `vec | bytes | bson`
+    #[arg(long)]
+    bytes: Option<String>,
+}
+
+impl From<GenerateArgs> for ImportOptions {
+    fn from(args: GenerateArgs) -> Self {
+        ImportOptions {
+            schema_paths: args.schema,
+            derive: args.derive,
+            include: args.include,
+            prefix: args.prefix,
+            patch: None,
+            relations: args.relations,
+            create_input: args.create_input,
+            update_input: args.update_input,
+            where_input: args.where_input,
+            field_enums: args.field_enums,
+            unique_keys: args.unique_keys,
+            id_newtypes: args.id_newtypes,
+            sha256: args.sha256,
+            offline: args.offline,
+            strict: args.strict,
+            datetime: args.datetime,
+            object_id: args.object_id,
+            json: args.json,
+            decimal: args.decimal,
+            bytes: args.bytes,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Some(Command::Generate(args)) => generate(args),
+        None => generator::run(),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let out = args.out.clone();
+    let check = args.check;
+
+    // Schema paths are relative to where the command is run, not to a `Cargo.toml`
+    let dir = std::env::current_dir().map_err(|e| e.to_string())?;
+    let generated = Generator::new(args.into())
+        .base_dir(dir)
+        .generate_output()
+        .map_err(|e| e.to_string())?;
+    print_warnings(&generated.warnings);
+    let code = generated.to_source().map_err(|e| e.to_string())?;
+
+    let Some(out) = out else {
+        print!("{HEADER}{code}");
+        return Ok(());
+    };
+
+    if check {
+        let existing = std::fs::read_to_string(&out).unwrap_or_default();
+        if existing != format!("{HEADER}{code}") {
+            return Err(format!(
+                "{} is out of date, run `prisma-rust-schema generate` without `--check` to update it",
+                out.display()
+            ));
+        }
+        return Ok(());
+    }
+
+    write(&out, &code)
+}
+
+/// Prints the invalid annotations which were ignored with `--strict false`
+fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("warning: {warning}");
+    }
+}
+
+/// Writes generated code to a file, with the header
+fn write(out: &Path, code: &str) -> Result<(), String> {
+    if let Some(parent) = out.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(out, format!("{HEADER}{code}")).map_err(|e| format!("{}: {e}", out.display()))
+}
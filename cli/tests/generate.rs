@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_prisma-rust-schema"))
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+        .args(args)
+        .output()
+        .expect("the binary to run")
+}
+
+fn out_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+#[test]
+fn generate_stdout() {
+    let output = run(&[
+        "generate",
+        "--schema",
+        "prisma/no-annotation-schema.prisma",
+        "--prefix",
+        "A",
+        "--derive",
+        "Debug,Clone",
+    ]);
+    assert!(output.status.success());
+
+    let code = String::from_utf8(output.stdout).unwrap();
+    assert!(code.starts_with("// @generated by prisma-rust-schema"));
+    assert!(code.contains("#[derive(Debug, Clone)]"));
+    assert!(code.contains("pub struct AUser {"));
+}
+
+#[test]
+fn generate_check() {
+    let out = out_path("check.rs");
+    let out = out.to_str().unwrap();
+    let args = ["generate", "--schema", "prisma/schema.prisma", "--out", out];
+    let check = [&args[..], &["--check"][..]].concat();
+
+    assert!(run(&args).status.success());
+    assert!(run(&check).status.success());
+
+    // A different option changes the output
+    let output = run(&[&check[..], &["--prefix", "B"][..]].concat());
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("is out of date")
+    );
+
+    // `--check` never writes the file
+    assert!(run(&check).status.success());
+}
+
+#[test]
+fn generate_check_missing_file() {
+    let out = out_path("missing.rs");
+    let _ = std::fs::remove_file(&out);
+
+    let output = run(&[
+        "generate",
+        "--schema",
+        "prisma/schema.prisma",
+        "--out",
+        out.to_str().unwrap(),
+        "--check",
+    ]);
+    assert!(!output.status.success());
+    assert!(!out.exists());
+}
+
+#[test]
+fn generate_invalid_schema() {
+    let output = run(&["generate", "--schema", "prisma/missing.prisma"]);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .starts_with("error:")
+    );
+}
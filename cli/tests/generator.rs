@@ -0,0 +1,92 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+/// Requests recorded from `prisma generate`, with the `output` replaced by `{{output}}`
+const FIXTURE: &str = include_str!("fixtures/generate.jsonl");
+
+/// Sends requests to the generator, and returns its responses
+fn run(requests: &str) -> Vec<Value> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_prisma-rust-schema"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("the binary to run");
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(requests.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stderr)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("a JSON-RPC response"))
+        .collect()
+}
+
+fn out_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+#[test]
+fn get_manifest_and_generate() {
+    let out = out_path("generator/models.rs");
+    let _ = std::fs::remove_file(&out);
+    let requests = FIXTURE.replace(
+        "{{output}}",
+        &out.display().to_string().replace('\\', "\\\\"),
+    );
+
+    let responses = run(&requests);
+    assert_eq!(responses.len(), 2);
+
+    assert_eq!(responses[0]["id"], 1);
+    assert_eq!(
+        responses[0]["result"]["manifest"]["prettyName"],
+        "Prisma Rust Schema"
+    );
+
+    assert_eq!(responses[1]["id"], 2);
+    assert_eq!(responses[1]["result"], Value::Null);
+
+    let code = std::fs::read_to_string(&out).unwrap();
+    assert!(code.starts_with("// @generated by prisma-rust-schema"));
+    assert!(code.contains("#[derive(Debug, Clone)]"));
+    assert!(code.contains("pub struct AUser {"));
+    assert!(code.contains("pub struct AUserCreateInput {"));
+}
+
+#[test]
+fn generate_error() {
+    let requests = FIXTURE
+        .lines()
+        .nth(1)
+        .unwrap()
+        .replace("{{output}}", "unused.rs")
+        .replace(r#""prefix":"A""#, r#""relations":"maybe""#);
+
+    let responses = run(&requests);
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0]["id"], 2);
+    assert!(
+        responses[0]["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("relations")
+    );
+}
+
+#[test]
+fn unknown_method() {
+    let responses = run(r#"{"jsonrpc":"2.0","method":"unknown","params":{},"id":3}"#);
+    assert_eq!(responses[0]["id"], 3);
+    assert!(responses[0]["error"].is_object());
+}
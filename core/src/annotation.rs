@@ -13,6 +13,12 @@ pub struct FieldAnnotation {
     pub type_: Option<String>,
     /// The visibility of the field
     pub visibility: Visibility,
+    /// Whether to include (`true`) or skip (`false`) a relation field
+    pub relation: Option<bool>,
+    /// The Rust expression used for the field in a generated `Default` impl
+    pub default: Option<String>,
+    /// Whether to generate an ID newtype for this `@id` field
+    pub newtype: bool,
 }
 
 #[derive(Debug, Default)]
@@ -35,6 +41,8 @@ pub struct EnumAnnotation {
 pub struct EnumValueAnnotation {
     pub rename: Option<String>,
     pub skip: bool,
+    /// Whether this is the `#[default]` variant, when `Default` is derived
+    pub default: bool,
 }
 
 #[derive(Debug, Default)]
@@ -84,6 +92,9 @@ impl FromStr for FieldAnnotation {
         let mut rename = None;
         let mut visibility = Visibility::default();
         let mut type_ = None;
+        let mut relation = None;
+        let mut default = None;
+        let mut newtype = false;
 
         for line in s.lines() {
             if line.trim().starts_with("@prs.") {
@@ -95,11 +106,43 @@ impl FromStr for FieldAnnotation {
                         skip = val.trim() != "false";
                     }
                     "type" => {
+                        syn::parse_str::<syn::Type>(val.trim()).map_err(|e| {
+                            format!(
+                                "Unable to parse `@prs.type` as a Rust type: {}\n{e}",
+                                val.trim()
+                            )
+                        })?;
                         type_ = Some(val.trim().to_string());
                     }
                     "rename" => {
                         rename = Some(val.trim().to_string());
                     }
+                    "default" => {
+                        syn::parse_str::<syn::Expr>(val.trim()).map_err(|e| {
+                            format!(
+                                "Unable to parse `@prs.default` as a Rust expression: {}\n{e}",
+                                val.trim()
+                            )
+                        })?;
+                        default = Some(val.trim().to_string());
+                    }
+                    "newtype" => {
+                        newtype = val.trim() != "false";
+                    }
+                    "relation" => {
+                        relation = match val.trim() {
+                            "include" => Some(true),
+                            "skip" => Some(false),
+                            _ => {
+                                let f = format!(
+                                    "Unknown relation: {}
+Available options: include, skip",
+                                    val
+                                );
+                                return Err(f);
+                            }
+                        };
+                    }
                     "visibility" => {
                         visibility = match val.trim() {
                             "private" => Visibility::Private,
@@ -111,14 +154,12 @@ impl FromStr for FieldAnnotation {
 Available options: private, protected (pub(crate)), public (pub)",
                                     val
                                 );
-                                eprintln!("{f}");
                                 return Err(f);
                             }
                         };
                     }
                     _ => {
-                        let f = format!("Unknown field annotation: {}", func);
-                        eprintln!("{f}");
+                        let f = format!("Unknown annotation: {}", func);
                         return Err(f);
                     }
                 }
@@ -130,23 +171,26 @@ Available options: private, protected (pub(crate)), public (pub)",
             skip,
             type_,
             visibility,
+            relation,
+            default,
+            newtype,
         })
     }
 }
 
 impl From<String> for FieldAnnotation {
     fn from(s: String) -> Self {
-        FieldAnnotation::from_str(&s).unwrap_or_default()
+        parse_lenient(&s)
     }
 }
 impl From<&String> for FieldAnnotation {
     fn from(s: &String) -> Self {
-        FieldAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 impl From<&str> for FieldAnnotation {
     fn from(s: &str) -> Self {
-        FieldAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 
@@ -182,7 +226,6 @@ impl FromStr for ModelAnnotation {
 Available options: private, protected (pub(crate)), public (pub)",
                                     val
                                 );
-                                eprintln!("{f}");
                                 return Err(f);
                             }
                         };
@@ -190,20 +233,12 @@ Available options: private, protected (pub(crate)), public (pub)",
                     "derive" => {
                         let derive_str = val.trim();
                         if derive_str.is_empty() {
-                            let f = format!("Derive for {func} cannot be empty");
-                            eprintln!("{f}");
-                            return Err(f);
+                            return Err(format!("Derive for {func} cannot be empty"));
                         }
-                        derive = Some(
-                            derive_str
-                                .split(',')
-                                .map(|s| s.trim().to_string())
-                                .collect(),
-                        );
+                        derive = Some(parse_derive(derive_str)?);
                     }
                     _ => {
-                        let f = format!("Unknown field annotation: {}", func);
-                        eprintln!("{f}");
+                        let f = format!("Unknown annotation: {}", func);
                         return Err(f);
                     }
                 }
@@ -221,17 +256,17 @@ Available options: private, protected (pub(crate)), public (pub)",
 
 impl From<String> for ModelAnnotation {
     fn from(s: String) -> Self {
-        ModelAnnotation::from_str(&s).unwrap_or_default()
+        parse_lenient(&s)
     }
 }
 impl From<&String> for ModelAnnotation {
     fn from(s: &String) -> Self {
-        ModelAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 impl From<&str> for ModelAnnotation {
     fn from(s: &str) -> Self {
-        ModelAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 
@@ -267,7 +302,6 @@ impl FromStr for EnumAnnotation {
 Available options: private, protected (pub(crate)), public (pub)",
                                     val
                                 );
-                                eprintln!("{f}");
                                 return Err(f);
                             }
                         };
@@ -275,21 +309,13 @@ Available options: private, protected (pub(crate)), public (pub)",
                     "derive" => {
                         let derive_str = val.trim();
                         if derive_str.is_empty() {
-                            let f = format!("Derive for {func} cannot be empty");
-                            eprintln!("{f}");
-                            return Err(f);
+                            return Err(format!("Derive for {func} cannot be empty"));
                         }
-                        derive = Some(
-                            derive_str
-                                .split(',')
-                                .map(|s| s.trim().to_string())
-                                .collect(),
-                        );
+                        derive = Some(parse_derive(derive_str)?);
                     }
 
                     _ => {
-                        let f = format!("Unknown field annotation: {}", func);
-                        eprintln!("{f}");
+                        let f = format!("Unknown annotation: {}", func);
                         return Err(f);
                     }
                 }
@@ -307,17 +333,17 @@ Available options: private, protected (pub(crate)), public (pub)",
 
 impl From<String> for EnumAnnotation {
     fn from(s: String) -> Self {
-        EnumAnnotation::from_str(&s).unwrap_or_default()
+        parse_lenient(&s)
     }
 }
 impl From<&String> for EnumAnnotation {
     fn from(s: &String) -> Self {
-        EnumAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 impl From<&str> for EnumAnnotation {
     fn from(s: &str) -> Self {
-        EnumAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 
@@ -341,6 +367,12 @@ impl FromStr for TypeAnnotation {
                         skip = val.trim() != "false";
                     }
                     "type" => {
+                        syn::parse_str::<syn::Type>(val.trim()).map_err(|e| {
+                            format!(
+                                "Unable to parse `@prs.type` as a Rust type: {}\n{e}",
+                                val.trim()
+                            )
+                        })?;
                         type_ = Some(val.trim().to_string());
                     }
                     "rename" => {
@@ -357,7 +389,6 @@ impl FromStr for TypeAnnotation {
 Available options: private, protected (pub(crate)), public (pub)",
                                     val
                                 );
-                                eprintln!("{f}");
                                 return Err(f);
                             }
                         };
@@ -367,16 +398,10 @@ Available options: private, protected (pub(crate)), public (pub)",
                         if derive_str.is_empty() {
                             return Err("Derive cannot be empty".to_string());
                         }
-                        derive = Some(
-                            derive_str
-                                .split(',')
-                                .map(|s| s.trim().to_string())
-                                .collect(),
-                        );
+                        derive = Some(parse_derive(derive_str)?);
                     }
                     _ => {
-                        let f = format!("Unknown field annotation: {}", func);
-                        eprintln!("{f}");
+                        let f = format!("Unknown annotation: {}", func);
                         return Err(f);
                     }
                 }
@@ -395,17 +420,17 @@ Available options: private, protected (pub(crate)), public (pub)",
 
 impl From<String> for TypeAnnotation {
     fn from(s: String) -> Self {
-        TypeAnnotation::from_str(&s).unwrap_or_default()
+        parse_lenient(&s)
     }
 }
 impl From<&String> for TypeAnnotation {
     fn from(s: &String) -> Self {
-        TypeAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 impl From<&str> for TypeAnnotation {
     fn from(s: &str) -> Self {
-        TypeAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 
@@ -415,6 +440,7 @@ impl FromStr for EnumValueAnnotation {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut skip = false;
         let mut rename = None;
+        let mut default = false;
 
         for line in s.lines() {
             if line.trim().starts_with("@prs.") {
@@ -428,31 +454,69 @@ impl FromStr for EnumValueAnnotation {
                     "rename" => {
                         rename = Some(val.trim().to_string());
                     }
+                    "default" => {
+                        default = val.trim() != "false";
+                    }
                     _ => {
-                        let f = format!("Unknown field annotation: {}", func);
-                        eprintln!("{f}");
+                        let f = format!("Unknown annotation: {}", func);
                         return Err(f);
                     }
                 }
             }
         }
 
-        Ok(EnumValueAnnotation { skip, rename })
+        Ok(EnumValueAnnotation {
+            skip,
+            rename,
+            default,
+        })
     }
 }
 
 impl From<String> for EnumValueAnnotation {
     fn from(s: String) -> Self {
-        EnumValueAnnotation::from_str(&s).unwrap_or_default()
+        parse_lenient(&s)
     }
 }
 impl From<&String> for EnumValueAnnotation {
     fn from(s: &String) -> Self {
-        EnumValueAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
 impl From<&str> for EnumValueAnnotation {
     fn from(s: &str) -> Self {
-        EnumValueAnnotation::from_str(s).unwrap_or_default()
+        parse_lenient(s)
     }
 }
+
+fn parse_derive(derive: &str) -> Result<Vec<String>, String> {
+    derive
+        .split(',')
+        .map(|d| {
+            let d = d.trim();
+            syn::parse_str::<syn::Path>(d)
+                .map(|_| d.to_string())
+                .map_err(|e| format!("Unable to parse `@prs.derive` as a path: {d}\n{e}"))
+        })
+        .collect()
+}
+
+/// Parses an annotation, ignoring the `@prs.` lines which fail to parse.
+///
+/// The ignored lines are reported by `annotation_errors`, before any code is generated.
+fn parse_lenient<T: FromStr + Default>(s: &str) -> T {
+    let valid_lines = s
+        .lines()
+        .filter(|line| T::from_str(line).is_ok())
+        .collect::<Vec<_>>()
+        .join("\n");
+    T::from_str(&valid_lines).unwrap_or_default()
+}
+
+/// The `@prs.` lines of a documentation comment which fail to parse, with their error
+pub fn annotation_errors<T: FromStr<Err = String>>(documentation: &str) -> Vec<(&str, String)> {
+    documentation
+        .lines()
+        .filter_map(|line| T::from_str(line).err().map(|e| (line.trim(), e)))
+        .collect()
+}
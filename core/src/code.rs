@@ -0,0 +1,500 @@
+use crate::ImportOptions;
+use crate::annotation::{FieldAnnotation, ModelAnnotation, Visibility};
+use crate::transform::{
+    TypeMapping, convert_field_to_serde_as, convert_field_to_type, get_field_name, get_struct_name,
+    to_pascal_case,
+};
+use psl::schema_ast::ast::Top;
+use psl::schema_ast::ast::{Attribute, Expression, Field, Model, WithDocumentation, WithName};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Ident, Type, parse_str};
+
+/// A field of a generated struct
+pub struct StructField<'a> {
+    pub field: &'a Field,
+    pub name: Ident,
+    /// The Rust type, including `Option` and `Vec`
+    pub type_name: proc_macro2::TokenStream,
+    /// Whether the type is overridden with `@prs.type`
+    pub type_override: bool,
+    /// Documentation and serde attributes
+    pub attributes: proc_macro2::TokenStream,
+    pub visibility: Visibility,
+    pub is_relation: bool,
+    /// The `serde_with::serde_as` adapter of the field, which needs `#[serde_with::serde_as]` on its struct
+    pub serde_as: Option<String>,
+    /// The `@prs.default` expression
+    pub default: Option<String>,
+    /// The field from the `patch` import option, which overwrites the generated field
+    pub patch: Option<syn::Field>,
+    /// The ID newtype replacing the scalar type of an `@id` or foreign key field
+    pub newtype: Option<Ident>,
+}
+
+impl StructField<'_> {
+    /// The type of the field, or of its patch
+    pub fn rust_type(&self) -> proc_macro2::TokenStream {
+        match &self.patch {
+            Some(patch) => patch.ty.to_token_stream(),
+            None => self.type_name.clone(),
+        }
+    }
+}
+
+impl ToTokens for StructField<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        if let Some(patch) = &self.patch {
+            quote! {
+                #patch,
+            }
+            .to_tokens(tokens);
+            return;
+        }
+
+        let StructField {
+            name,
+            type_name,
+            attributes,
+            visibility,
+            serde_as,
+            ..
+        } = self;
+
+        let serde_as = serde_as.as_ref().map(|adapter| {
+            quote! {
+                #[serde_as(as = #adapter)]
+            }
+        });
+
+        quote! {
+            #attributes
+            #serde_as
+            #visibility #name: #type_name,
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub fn handle_fields<'a>(
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    type_mapping: &TypeMapping,
+    derive: &Option<Vec<String>>,
+    field: &'a Field,
+) -> Option<StructField<'a>> {
+    let FieldAnnotation {
+        skip,
+        rename,
+        visibility,
+        type_,
+        relation,
+        default,
+        ..
+    } = match field.documentation() {
+        Some(d) => d.into(),
+        None => FieldAnnotation::default(),
+    };
+
+    if skip {
+        return None;
+    }
+
+    // If field is a relation, skip unless relations are included
+    if is_relation(schema, &field) {
+        if !relation.unwrap_or(import_options.relations.unwrap_or(false)) {
+            return None;
+        }
+        return handle_relation_field(
+            schema,
+            import_options,
+            derive,
+            field,
+            rename,
+            visibility,
+            type_,
+            default,
+        );
+    }
+
+    let name = get_field_name(rename.unwrap_or(field.name().to_string()));
+
+    let serde_rename = if let Some(db_name) = get_map_attribute(&field.attributes) {
+        let s = quote! {
+            #[serde(rename = #db_name)]
+        };
+        Some(s)
+        // If field is renamed in Rust, the actual name should be used
+    } else if name != &field.name() {
+        let original_name = field.name();
+        let s = quote! {
+            #[serde(rename = #original_name)]
+        };
+        Some(s)
+    } else {
+        None
+    };
+
+    let type_override = type_.is_some();
+    let type_name = match type_ {
+        Some(type_) => {
+            let t: Type = parse_str(&type_).expect("`@prs.type` to be validated");
+            quote! { #t }
+        }
+        None => {
+            // Handle type conversions like `Int` to `i32`, and `field.native_type: ObjectId` to `bson::oid::ObjectId`
+            let converted_type = convert_field_to_type(field, &import_options, type_mapping);
+            let t: Type = parse_str(&converted_type).expect("type to be parseable");
+
+            quote! { #t }
+        }
+    };
+
+    // Only applies to generated types. An overridden type is (de)serialized as-is
+    let serde_as = if type_override {
+        None
+    } else {
+        convert_field_to_serde_as(field, &import_options, type_mapping)
+    };
+
+    let documentation = extract_docs(field.documentation().clone());
+
+    return Some(StructField {
+        field,
+        name,
+        type_name,
+        type_override,
+        attributes: quote! {
+            #documentation
+            #serde_rename
+        },
+        visibility,
+        is_relation: false,
+        serde_as,
+        default,
+        patch: None,
+        newtype: None,
+    });
+}
+
+/// A relation field is only populated when the relation is loaded (e.g. with Prisma `include`),
+/// so it is always optional. `Box` and `Vec` allow self- and mutually-recursive models.
+/// A `@prs.type` override is used as written.
+fn handle_relation_field<'a>(
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    derive: &Option<Vec<String>>,
+    field: &'a Field,
+    rename: Option<String>,
+    visibility: Visibility,
+    type_: Option<String>,
+    default: Option<String>,
+) -> Option<StructField<'a>> {
+    let name = get_field_name(rename.unwrap_or(field.name().to_string()));
+
+    // Without a serde derive, the `serde` attribute is not in scope
+    let serde = derives_serde(derive);
+
+    let serde_rename = if serde && name != &field.name() {
+        let original_name = field.name();
+        Some(quote! {
+            #[serde(rename = #original_name)]
+        })
+    } else {
+        None
+    };
+
+    let type_override = type_.is_some();
+    let type_name = match type_ {
+        Some(type_) => {
+            let t: Type = parse_str(&type_).expect("`@prs.type` to be validated");
+            quote! { #t }
+        }
+        None => {
+            // The related model is not generated, so there is nothing to deserialize into
+            let model_name =
+                get_model_struct_name(schema, field.field_type.name(), import_options)?;
+            if field.arity.is_list() {
+                quote! { Option<Vec<#model_name>> }
+            } else {
+                quote! { Option<Box<#model_name>> }
+            }
+        }
+    };
+
+    let skip_none = if serde && !type_override {
+        Some(quote! { #[serde(default, skip_serializing_if = "Option::is_none")] })
+    } else {
+        None
+    };
+
+    let documentation = extract_docs(field.documentation().clone());
+
+    Some(StructField {
+        field,
+        name,
+        type_name,
+        type_override,
+        attributes: quote! {
+            #documentation
+            #serde_rename
+            #skip_none
+        },
+        visibility,
+        is_relation: true,
+        serde_as: None,
+        default,
+        patch: None,
+        newtype: None,
+    })
+}
+
+/// The generated struct name of a model, or `None` if the model is not generated.
+pub fn get_model_struct_name(
+    schema: &Vec<Top>,
+    name: &str,
+    import_options: &ImportOptions,
+) -> Option<Ident> {
+    if let Some(include) = &import_options.include {
+        if !include.iter().any(|i| i == name) {
+            return None;
+        }
+    }
+
+    schema.iter().find_map(|top| match top {
+        Top::Model(model) if model.name() == name => {
+            let ModelAnnotation { skip, rename, .. } = match model.documentation() {
+                Some(d) => d.into(),
+                None => ModelAnnotation::default(),
+            };
+            if skip {
+                return None;
+            }
+            Some(get_struct_name(
+                rename.unwrap_or(name.to_string()),
+                import_options,
+            ))
+        }
+        _ => None,
+    })
+}
+
+/// Adds `#[serde_with::serde_as]` to a struct if any of its fields need a `serde_as` adapter.
+///
+/// Must be placed before the `derive` attribute.
+pub fn handle_serde_as(fields: &[StructField]) -> impl ToTokens {
+    if fields
+        .iter()
+        .any(|field| field.serde_as.is_some() && field.patch.is_none())
+    {
+        quote! { #[serde_with::serde_as] }
+    } else {
+        quote! {}
+    }
+}
+
+/// Overwrites the fields explicitly mentioned in the `patch` import option for a struct
+pub fn apply_patch(
+    fields: &mut [StructField],
+    import_options: &ImportOptions,
+    struct_name: &Ident,
+) {
+    let Some(patch) = import_options
+        .patch
+        .iter()
+        .flatten()
+        .find(|p| &p.ident == struct_name)
+    else {
+        return;
+    };
+
+    for field in fields.iter_mut() {
+        if let Some(f) = patch
+            .fields
+            .iter()
+            .find(|f| f.ident.as_ref() == Some(&field.name))
+        {
+            field.patch = Some(f.clone());
+        }
+    }
+}
+
+/// `<Struct>Field`, an enum of the (non-relation) fields of a struct, with their database and Rust names
+pub fn handle_field_enum(
+    struct_name: &Ident,
+    visibility: Visibility,
+    fields: &[StructField],
+) -> proc_macro2::TokenStream {
+    let enum_name = format_ident!("{}Field", struct_name);
+    let fields = fields
+        .iter()
+        .filter(|field| !field.is_relation)
+        .collect::<Vec<_>>();
+
+    let variants = fields
+        .iter()
+        .map(|field| format_ident!("{}", to_pascal_case(&field.name.to_string())))
+        .collect::<Vec<_>>();
+    let db_names = fields
+        .iter()
+        .map(|field| get_map_attribute(&field.field.attributes).unwrap_or(field.field.name()));
+    let rust_names = fields.iter().map(|field| field.name.to_string());
+
+    quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #visibility enum #enum_name {
+            #(#variants,)*
+        }
+
+        impl #enum_name {
+            /// Every field, in schema order
+            #visibility const ALL: &'static [#enum_name] = &[#(#enum_name::#variants),*];
+
+            /// The name of the field in the database (`@map`), or the Prisma field name
+            #visibility const fn as_str(&self) -> &'static str {
+                match *self {
+                    #(#enum_name::#variants => #db_names,)*
+                }
+            }
+
+            /// The name of the field in the generated struct
+            #visibility const fn rust_name(&self) -> &'static str {
+                match *self {
+                    #(#enum_name::#variants => #rust_names,)*
+                }
+            }
+        }
+    }
+}
+
+/// The database name from a `@map("...")` (or `@@map("...")`) attribute
+pub fn get_map_attribute(attributes: &[Attribute]) -> Option<&str> {
+    attributes.iter().find_map(|a| {
+        if a.name() == "map" {
+            let (val, _) = a.arguments.arguments[0]
+                .value
+                .as_string_value()
+                .expect("map attribute to take string value");
+            Some(val)
+        } else {
+            None
+        }
+    })
+}
+
+/// The names of the fields in the primary key of a model, from `@id` or `@@id([...])`
+pub fn get_id_fields(model: &Model) -> Vec<&str> {
+    if let Some(attribute) = model.attributes.iter().find(|a| a.name() == "id") {
+        return get_field_list(attribute);
+    }
+
+    model
+        .iter_fields()
+        .filter(|(_, field)| field.attributes.iter().any(|a| a.name() == "id"))
+        .map(|(_, field)| field.name())
+        .collect()
+}
+
+/// The field names of a block attribute, e.g. `@@id([a, b])` or `@@unique(fields: [a, b])`
+pub fn get_field_list(attribute: &Attribute) -> Vec<&str> {
+    get_argument_list(attribute, |name| name.is_none_or(|name| name == "fields"))
+}
+
+/// The field names of a named attribute argument, e.g. `references` in `@relation(fields: [a], references: [id])`
+pub fn get_named_field_list<'a>(attribute: &'a Attribute, argument_name: &str) -> Vec<&'a str> {
+    get_argument_list(attribute, |name| name == Some(argument_name))
+}
+
+fn get_argument_list(
+    attribute: &Attribute,
+    is_argument: impl Fn(Option<&str>) -> bool,
+) -> Vec<&str> {
+    let Some(argument) = attribute
+        .arguments
+        .arguments
+        .iter()
+        .find(|arg| is_argument(arg.name.as_ref().map(|name| name.name.as_str())))
+    else {
+        return vec![];
+    };
+
+    match &argument.value {
+        Expression::Array(values, _) => values
+            .iter()
+            .filter_map(|value| match value {
+                Expression::ConstantValue(name, _) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+pub fn extract_docs(documentation: Option<&str>) -> impl ToTokens {
+    if let Some(doc) = documentation {
+        let docs = doc.lines().filter_map(|line| {
+            let line = line.trim();
+            if line.starts_with("@prs.") {
+                return None;
+            }
+            Some(quote! {#[doc = #line]})
+        });
+
+        let doc = quote! {
+            #(#docs)*
+        };
+        doc
+    } else {
+        // An empty ident
+        let ident: Option<Ident> = None;
+        quote! { #ident }
+    }
+}
+
+pub fn handle_derive(derive: Option<Vec<String>>) -> impl ToTokens {
+    if let Some(derive) = derive {
+        let derive = derive
+            .iter()
+            .map(|d| {
+                let derive_type: Type = parse_str(d.trim()).expect("derive input to be parseable");
+                quote! { #derive_type }
+            })
+            .collect::<Vec<_>>();
+        let derive = quote! {
+            #[derive(#(#derive),*)]
+        };
+        derive
+    } else {
+        let ident: Option<Ident> = None;
+        quote! { #ident }
+    }
+}
+
+/// Whether a derive list contains a trait, by the last segment of its path (e.g. `serde::Serialize`)
+pub fn derives(derive: &Option<Vec<String>>, name: &str) -> bool {
+    derive.as_ref().is_some_and(|derive| {
+        derive
+            .iter()
+            .any(|d| d.trim().rsplit("::").next() == Some(name))
+    })
+}
+
+/// Whether a derive list contains `Serialize` or `Deserialize`, which bring the `serde` attribute into scope
+pub fn derives_serde(derive: &Option<Vec<String>>) -> bool {
+    derives(derive, "Serialize") || derives(derive, "Deserialize")
+}
+
+/// If a field is a model or an explicit relation.
+pub fn is_relation(schema: &Vec<Top>, field: &Field) -> bool {
+    for top in schema {
+        match top {
+            Top::Model(model) => {
+                if model.name() == field.field_type.name() {
+                    return true;
+                }
+            }
+            _ => {}
+        };
+    }
+
+    field.attributes.iter().any(|a| a.name() == "relation")
+}
@@ -0,0 +1,266 @@
+use psl::schema_ast::ast::{Expression, GeneratorConfig, Top};
+
+use crate::ImportOptions;
+use crate::transform::to_snake_case;
+
+/// The `provider` of this crate's `generator` block contains this, e.g. `"prisma-rust-schema"` or `"./target/release/prisma-rust-schema"`
+const PROVIDER: &str = "prisma-rust-schema";
+/// The name of this crate's `generator` block, with any other provider
+const GENERATOR_NAME: &str = "prisma_rust_schema";
+
+/// The value of a Prisma `generator` block property
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    String(String),
+    List(Vec<String>),
+}
+
+impl ConfigValue {
+    fn into_string(self, key: &str) -> Result<String, String> {
+        match self {
+            ConfigValue::String(s) => Ok(s),
+            ConfigValue::List(_) => Err(format!("Expected `{key}` to be a string, not a list")),
+        }
+    }
+
+    fn into_bool(self, key: &str) -> Result<bool, String> {
+        match self.into_string(key)?.as_str() {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            s => Err(format!(
+                "Expected `{key}` to be `true` or `false`, not `{s}`"
+            )),
+        }
+    }
+
+    /// A list, or a comma-separated string
+    fn into_list(self) -> Vec<String> {
+        match self {
+            ConfigValue::String(s) => s
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            ConfigValue::List(list) => list,
+        }
+    }
+}
+
+/// The `generator` block properties used by Prisma itself
+const PRISMA_PROPERTIES: [&str; 4] = ["provider", "output", "binary_targets", "preview_features"];
+
+impl ImportOptions {
+    /// The import options of a Prisma `generator` block, e.g.
+    /// `generator rust { provider = "prisma-rust-schema" prefix = "A" derive = "Debug,Clone" }`
+    ///
+    /// Keys are the import option names, in snake case or camel case (`create_input` or `createInput`).
+    /// `derive` and `include` are lists or comma-separated strings, and booleans are `"true"` or `"false"`.
+    /// `schema_paths` and `patch` can not be set, and `sha256` and `offline` are errors, as they only apply to loading the schema.
+    pub fn from_config<K: AsRef<str>>(
+        config: impl IntoIterator<Item = (K, ConfigValue)>,
+    ) -> Result<Self, String> {
+        let mut import_options = ImportOptions::default();
+
+        for (key, value) in config {
+            let key = to_snake_case(key.as_ref());
+            let key = key.as_str();
+            match key {
+                "derive" => {
+                    let derive = value.into_list();
+                    if let Some(d) = derive
+                        .iter()
+                        .find(|d| syn::parse_str::<syn::Path>(d).is_err())
+                    {
+                        return Err(format!("Unable to parse `derive` as a path: {d}"));
+                    }
+                    import_options.derive = Some(derive);
+                }
+                "include" => import_options.include = Some(value.into_list()),
+                "prefix" => import_options.prefix = Some(value.into_string(key)?),
+                "relations" => import_options.relations = Some(value.into_bool(key)?),
+                "create_input" => import_options.create_input = Some(value.into_bool(key)?),
+                "update_input" => import_options.update_input = Some(value.into_bool(key)?),
+                "where_input" => import_options.where_input = Some(value.into_bool(key)?),
+                "field_enums" => import_options.field_enums = Some(value.into_bool(key)?),
+                "unique_keys" => import_options.unique_keys = Some(value.into_bool(key)?),
+                "id_newtypes" => import_options.id_newtypes = Some(value.into_bool(key)?),
+                "sha256" | "offline" => {
+                    return Err(format!(
+                        "`{key}` can not be set in a generator block, because the schema is loaded before the block is read. Set it on `import_types!` instead"
+                    ));
+                }
+                "strict" => import_options.strict = Some(value.into_bool(key)?),
+                "datetime" => import_options.datetime = Some(value.into_string(key)?),
+                "object_id" => import_options.object_id = Some(value.into_string(key)?),
+                "json" => import_options.json = Some(value.into_string(key)?),
+                "decimal" => import_options.decimal = Some(value.into_string(key)?),
+                "bytes" => import_options.bytes = Some(value.into_string(key)?),
+                _ if PRISMA_PROPERTIES.contains(&key) => {}
+                _ => return Err(format!("Unknown generator config: {key}")),
+            }
+        }
+
+        Ok(import_options)
+    }
+
+    /// Each option which is not set, from `defaults`
+    pub fn or(self, defaults: ImportOptions) -> Self {
+        ImportOptions {
+            schema_paths: if self.schema_paths.is_empty() {
+                defaults.schema_paths
+            } else {
+                self.schema_paths
+            },
+            derive: self.derive.or(defaults.derive),
+            include: self.include.or(defaults.include),
+            prefix: self.prefix.or(defaults.prefix),
+            patch: self.patch.or(defaults.patch),
+            relations: self.relations.or(defaults.relations),
+            create_input: self.create_input.or(defaults.create_input),
+            update_input: self.update_input.or(defaults.update_input),
+            where_input: self.where_input.or(defaults.where_input),
+            field_enums: self.field_enums.or(defaults.field_enums),
+            unique_keys: self.unique_keys.or(defaults.unique_keys),
+            id_newtypes: self.id_newtypes.or(defaults.id_newtypes),
+            sha256: self.sha256.or(defaults.sha256),
+            offline: self.offline.or(defaults.offline),
+            strict: self.strict.or(defaults.strict),
+            datetime: self.datetime.or(defaults.datetime),
+            object_id: self.object_id.or(defaults.object_id),
+            json: self.json.or(defaults.json),
+            decimal: self.decimal.or(defaults.decimal),
+            bytes: self.bytes.or(defaults.bytes),
+        }
+    }
+}
+
+/// The `generator` block of this crate, by its `provider` or its name
+pub fn find_generator(tops: &[Top]) -> Option<&GeneratorConfig> {
+    tops.iter().find_map(|top| match top {
+        Top::Generator(generator) => {
+            let provider = generator
+                .properties
+                .iter()
+                .find(|property| property.name.name == "provider")
+                .and_then(|property| property.value.as_ref())
+                .and_then(|value| value.as_string_value())
+                .map(|(provider, _)| provider);
+
+            (generator.name.name == GENERATOR_NAME
+                || provider.is_some_and(|provider| provider.contains(PROVIDER)))
+            .then_some(generator)
+        }
+        _ => None,
+    })
+}
+
+/// The properties of a `generator` block. Properties without a value, or with a function value such as `env("OUTPUT")`, are skipped
+pub fn generator_config(generator: &GeneratorConfig) -> Vec<(String, ConfigValue)> {
+    generator
+        .properties
+        .iter()
+        .filter_map(|property| {
+            let value = match property.value.as_ref()? {
+                Expression::Array(values, _) => {
+                    ConfigValue::List(values.iter().filter_map(expression_string).collect())
+                }
+                value => ConfigValue::String(expression_string(value)?),
+            };
+            Some((property.name.name.clone(), value))
+        })
+        .collect()
+}
+
+/// Strings, and constants and numbers as written, e.g. `true`
+fn expression_string(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::StringValue(s, _)
+        | Expression::ConstantValue(s, _)
+        | Expression::NumericValue(s, _) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(s: &str) -> ConfigValue {
+        ConfigValue::String(s.to_string())
+    }
+
+    #[test]
+    fn test_from_config() {
+        let import_options = ImportOptions::from_config([
+            ("provider", string("prisma-rust-schema")),
+            ("prefix", string("A")),
+            ("derive", string("Debug, Clone,serde::Serialize")),
+            (
+                "include",
+                ConfigValue::List(vec!["User".to_string(), "Post".to_string()]),
+            ),
+            ("createInput", string("true")),
+            ("where_input", string("false")),
+            ("datetime", string("chrono")),
+        ])
+        .unwrap();
+
+        assert_eq!(import_options.prefix.as_deref(), Some("A"));
+        assert_eq!(
+            import_options.derive,
+            Some(vec![
+                "Debug".to_string(),
+                "Clone".to_string(),
+                "serde::Serialize".to_string()
+            ])
+        );
+        assert_eq!(
+            import_options.include,
+            Some(vec!["User".to_string(), "Post".to_string()])
+        );
+        assert_eq!(import_options.create_input, Some(true));
+        assert_eq!(import_options.where_input, Some(false));
+        assert_eq!(import_options.datetime.as_deref(), Some("chrono"));
+        assert!(import_options.schema_paths.is_empty());
+    }
+
+    #[test]
+    fn test_or() {
+        let import_options = ImportOptions {
+            prefix: Some("A".to_string()),
+            relations: Some(false),
+            ..Default::default()
+        }
+        .or(ImportOptions {
+            schema_paths: vec!["schema.prisma".to_string()],
+            prefix: Some("B".to_string()),
+            relations: Some(true),
+            create_input: Some(true),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            import_options.schema_paths,
+            vec!["schema.prisma".to_string()]
+        );
+        assert_eq!(import_options.prefix.as_deref(), Some("A"));
+        assert_eq!(import_options.relations, Some(false));
+        assert_eq!(import_options.create_input, Some(true));
+        assert_eq!(import_options.update_input, None);
+    }
+
+    #[test]
+    fn test_from_config_errors() {
+        assert!(ImportOptions::from_config([("relations", string("yes"))]).is_err());
+        assert!(ImportOptions::from_config([("prefix", ConfigValue::List(vec![]))]).is_err());
+        assert!(ImportOptions::from_config([("derive", string("Debug, 1"))]).is_err());
+        assert!(ImportOptions::from_config([("schema_paths", string("a.prisma"))]).is_err());
+        assert!(ImportOptions::from_config([("unknown", string(""))]).is_err());
+        assert!(
+            ImportOptions::from_config([("offline", string("true"))])
+                .unwrap_err()
+                .contains("import_types!")
+        );
+        assert!(ImportOptions::from_config([("sha256", string("9f86d0"))]).is_err());
+    }
+}
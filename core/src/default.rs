@@ -0,0 +1,236 @@
+use psl::parser_database::ParserDatabase;
+use psl::schema_ast::ast::{Expression, Top, WithDocumentation, WithName, WithSpan};
+use quote::{format_ident, quote};
+use syn::{Expr, Ident, Path, parse_str};
+
+use crate::ImportOptions;
+use crate::annotation::EnumValueAnnotation;
+use crate::code::StructField;
+use crate::diagnostic::span_error;
+use crate::transform::{TypeMapping, convert_field_to_scalar};
+
+/// Whether a derive list contains `Default`, e.g. `Default` or `std::default::Default`
+pub fn derives_default(derive: &Option<Vec<String>>) -> bool {
+    derive
+        .as_ref()
+        .is_some_and(|derive| derive.iter().any(|d| is_default(d)))
+}
+
+/// Removes `Default` from a derive list, so it can be implemented from `@default` attributes instead.
+pub fn take_default(derive: Option<Vec<String>>) -> (Option<Vec<String>>, bool) {
+    let derives_default = derives_default(&derive);
+    let derive = derive.map(|derive| derive.into_iter().filter(|d| !is_default(d)).collect());
+    (derive, derives_default)
+}
+
+fn is_default(derive: &str) -> bool {
+    derive.trim().rsplit("::").next() == Some("Default")
+}
+
+/// `impl Default` using the `@prs.default` and `@default(...)` of each field.
+///
+/// Fields without either, patched fields, and defaults which cannot be generated (e.g. `cuid()`) use `Default::default()`.
+/// A default enum value which is skipped with `@prs.skip` is an error.
+pub fn handle_default_impl(
+    db: &ParserDatabase,
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    type_mapping: &TypeMapping,
+    struct_name: &Ident,
+    fields: &[StructField],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let field_defaults = fields
+        .iter()
+        .map(|struct_field| {
+            let name = &struct_field.name;
+            let value = if struct_field.patch.is_some() {
+                quote! { Default::default() }
+            } else {
+                field_default(db, schema, import_options, type_mapping, struct_field)?
+            };
+            Ok(quote! {
+                #name: #value,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl Default for #struct_name {
+            fn default() -> Self {
+                Self {
+                    #(#field_defaults)*
+                }
+            }
+        }
+    })
+}
+
+fn field_default(
+    db: &ParserDatabase,
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    type_mapping: &TypeMapping,
+    struct_field: &StructField,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some(default) = &struct_field.default {
+        let expr: Expr = parse_str(default).expect("`@prs.default` to be an expression");
+        return Ok(quote! { #expr });
+    }
+
+    // The type is unknown, so a Prisma default cannot be converted
+    if struct_field.is_relation || struct_field.type_override {
+        return Ok(quote! { Default::default() });
+    }
+
+    let field = struct_field.field;
+    let Some(value) = field
+        .attributes
+        .iter()
+        .find(|a| a.name() == "default")
+        .and_then(|a| a.arguments.arguments.first())
+    else {
+        return Ok(quote! { Default::default() });
+    };
+
+    if let Some(skipped) = skipped_enum_value(schema, field.field_type.name(), &value.value) {
+        return Err(span_error(
+            db,
+            field.span(),
+            format!(
+                "The default of `{}` is `{skipped}`, which is skipped with `@prs.skip`",
+                field.name()
+            ),
+        ));
+    }
+
+    let scalar = convert_field_to_scalar(field, import_options, type_mapping);
+    let value = match convert_default(schema, field.field_type.name(), &scalar, &value.value) {
+        Some(value) => match &struct_field.newtype {
+            Some(newtype) => quote! { #newtype(#value) },
+            None => value,
+        },
+        None => return Ok(quote! { Default::default() }),
+    };
+    if field.arity.is_optional() {
+        Ok(quote! { Some(#value) })
+    } else {
+        Ok(value)
+    }
+}
+
+/// The first value of a `@default(...)` which is an enum value with `@prs.skip`, including in a list default
+fn skipped_enum_value<'a>(
+    schema: &Vec<Top>,
+    enum_name: &str,
+    value: &'a Expression,
+) -> Option<&'a str> {
+    match value {
+        Expression::ConstantValue(constant, _) => {
+            let skipped = schema.iter().any(|top| match top {
+                Top::Enum(enum_type) if enum_type.name() == enum_name => enum_type
+                    .values
+                    .iter()
+                    .filter(|v| v.name() == constant)
+                    .any(|v| {
+                        v.documentation()
+                            .is_some_and(|d| EnumValueAnnotation::from(d).skip)
+                    }),
+                _ => false,
+            });
+            skipped.then_some(constant.as_str())
+        }
+        Expression::Array(values, _) => values
+            .iter()
+            .find_map(|value| skipped_enum_value(schema, enum_name, value)),
+        _ => None,
+    }
+}
+
+/// Converts a `@default(...)` value into a Rust expression of type `scalar`
+fn convert_default(
+    schema: &Vec<Top>,
+    prisma_type: &str,
+    scalar: &str,
+    value: &Expression,
+) -> Option<proc_macro2::TokenStream> {
+    let value = match value {
+        Expression::ConstantValue(constant, _) => match scalar {
+            "bool" => {
+                let b: Expr = parse_str(constant).ok()?;
+                quote! { #b }
+            }
+            // Enum variant
+            _ => {
+                let enum_path: Path = parse_str(scalar).ok()?;
+                let variant = get_enum_variant(schema, prisma_type, constant)?;
+                quote! { #enum_path::#variant }
+            }
+        },
+        Expression::NumericValue(number, _) => match scalar {
+            "f32" | "f64" => {
+                let number = if number.contains(['.', 'e', 'E']) {
+                    number.to_string()
+                } else {
+                    format!("{number}.0")
+                };
+                let n: Expr = parse_str(&number).ok()?;
+                quote! { #n }
+            }
+            "String" => quote! { #number.to_string() },
+            "rust_decimal::Decimal" | "bigdecimal::BigDecimal" => {
+                quote! { #number.parse().expect("default to be a valid decimal") }
+            }
+            _ => {
+                let n: Expr = parse_str(number).ok()?;
+                quote! { #n }
+            }
+        },
+        Expression::StringValue(string, _) => match scalar {
+            "String" => quote! { #string.to_string() },
+            "serde_json::Value" => {
+                quote! { serde_json::from_str(#string).expect("default to be valid JSON") }
+            }
+            "rust_decimal::Decimal" | "bigdecimal::BigDecimal" => {
+                quote! { #string.parse().expect("default to be a valid decimal") }
+            }
+            _ => return None,
+        },
+        Expression::Function(name, _, _) => match (name.as_str(), scalar) {
+            ("now", "chrono::DateTime<chrono::Utc>") => quote! { chrono::Utc::now() },
+            ("now", "bson::DateTime") => quote! { bson::DateTime::now() },
+            ("now", "time::OffsetDateTime") => quote! { time::OffsetDateTime::now_utc() },
+            ("now", "jiff::Timestamp") => quote! { jiff::Timestamp::now() },
+            ("auto", "bson::oid::ObjectId") => quote! { bson::oid::ObjectId::new() },
+            ("uuid", "uuid::Uuid") => quote! { uuid::Uuid::new_v4() },
+            // `autoincrement()`, `cuid()`, `nanoid()`, `dbgenerated()`, and `String` backends
+            _ => return None,
+        },
+        Expression::Array(values, _) => {
+            let values = values
+                .iter()
+                .map(|value| convert_default(schema, prisma_type, scalar, value))
+                .collect::<Option<Vec<_>>>()?;
+            quote! { vec![#(#values),*] }
+        }
+    };
+
+    Some(value)
+}
+
+/// The generated variant of a Prisma enum value
+fn get_enum_variant(schema: &Vec<Top>, enum_name: &str, value_name: &str) -> Option<Ident> {
+    schema.iter().find_map(|top| match top {
+        Top::Enum(enum_type) if enum_type.name() == enum_name => {
+            let enum_value = enum_type.values.iter().find(|v| v.name() == value_name)?;
+            let EnumValueAnnotation { rename, .. } = match enum_value.documentation() {
+                Some(d) => d.into(),
+                None => EnumValueAnnotation::default(),
+            };
+            Some(format_ident!(
+                "{}",
+                rename.unwrap_or(value_name.to_string())
+            ))
+        }
+        _ => None,
+    })
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+
+use psl::diagnostics::Span;
+use psl::parser_database::ParserDatabase;
+use psl::schema_ast::ast::{Top, WithDocumentation, WithName, WithSpan};
+
+use crate::annotation::{
+    EnumAnnotation, EnumValueAnnotation, FieldAnnotation, ModelAnnotation, TypeAnnotation,
+    annotation_errors,
+};
+
+/// The 1-based line and 1-based column of an offset in a schema file
+fn location(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// An invalid annotation, located in its schema file
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    pub location: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+/// Checks the `@prs.` annotations of every model, composite type, enum and their fields and values
+pub fn validate_annotations(tops: &[Top], db: &ParserDatabase) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut check =
+        |documentation: Option<&str>, span: Span, errors: fn(&str) -> Vec<(&str, String)>| {
+            let Some(documentation) = documentation else {
+                return;
+            };
+            for (line, message) in errors(documentation) {
+                diagnostics.push(diagnostic(db, span, line, message));
+            }
+        };
+
+    for top in tops {
+        match top {
+            Top::Model(model) => {
+                check(
+                    model.documentation(),
+                    model.span(),
+                    annotation_errors::<ModelAnnotation>,
+                );
+                for (_, field) in model.iter_fields() {
+                    check(
+                        field.documentation(),
+                        field.span(),
+                        annotation_errors::<FieldAnnotation>,
+                    );
+                }
+            }
+            Top::CompositeType(composite_type) => {
+                check(
+                    composite_type.documentation(),
+                    composite_type.span(),
+                    annotation_errors::<TypeAnnotation>,
+                );
+                check(
+                    composite_type.documentation(),
+                    composite_type.span(),
+                    |documentation| {
+                        documentation
+                            .lines()
+                            .filter(|line| line.trim().starts_with("@prs.type"))
+                            .map(|line| {
+                                (
+                                    line.trim(),
+                                    "`@prs.type` annotation is not supported for type name declarations.\nPlease use `@prs.rename` instead.".to_string(),
+                                )
+                            })
+                            .collect()
+                    },
+                );
+                for (_, field) in composite_type.iter_fields() {
+                    check(
+                        field.documentation(),
+                        field.span(),
+                        annotation_errors::<FieldAnnotation>,
+                    );
+                }
+            }
+            Top::Enum(enum_type) => {
+                check(
+                    enum_type.documentation(),
+                    enum_type.span(),
+                    annotation_errors::<EnumAnnotation>,
+                );
+                for enum_value in &enum_type.values {
+                    check(
+                        enum_value.documentation(),
+                        enum_value.span(),
+                        annotation_errors::<EnumValueAnnotation>,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    diagnostics
+}
+
+/// Strict mode fails with every diagnostic as a compile error. Lenient mode returns them as warnings, and ignores the invalid annotations.
+pub fn report(diagnostics: Vec<Diagnostic>, strict: bool) -> syn::Result<Vec<String>> {
+    if !strict {
+        return Ok(diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect());
+    }
+
+    diagnostics
+        .into_iter()
+        .map(|diagnostic| syn::Error::new(proc_macro2::Span::call_site(), diagnostic))
+        .reduce(|mut error, next| {
+            error.combine(next);
+            error
+        })
+        .map_or(Ok(vec![]), Err)
+}
+
+fn diagnostic(db: &ParserDatabase, span: Span, line: &str, message: String) -> Diagnostic {
+    let source = db.source(span.file_id);
+    let offset = find_doc_line(source, span.start, line);
+    Diagnostic {
+        location: file_location(db, span, offset),
+        message,
+    }
+}
+
+/// An error at the start of a span, e.g. an invalid `generator` block
+pub fn span_error(db: &ParserDatabase, span: Span, message: String) -> syn::Error {
+    let diagnostic = Diagnostic {
+        location: file_location(db, span, span.start),
+        message,
+    };
+    syn::Error::new(proc_macro2::Span::call_site(), diagnostic)
+}
+
+/// `file:line:column` of an offset in the file of a span
+fn file_location(db: &ParserDatabase, span: Span, offset: usize) -> String {
+    let name = db.file_name(span.file_id);
+    let (line, column) = location(db.source(span.file_id), offset);
+    format!("{name}:{line}:{column}")
+}
+
+/// The offset of a documentation line of the item starting at `span_start`.
+///
+/// Documentation comments are the comment lines right above the item, or the start of its span. Falls back to `span_start`.
+fn find_doc_line(text: &str, span_start: usize, doc_line: &str) -> usize {
+    let mut lines = vec![];
+    let mut offset = 0;
+    for line in text.split('\n') {
+        lines.push((offset, line));
+        offset += line.len() + 1;
+    }
+    let Some(item_line) = lines
+        .iter()
+        .rposition(|(line_offset, _)| *line_offset <= span_start)
+    else {
+        return span_start;
+    };
+
+    let is_comment = |(_, line): &&(usize, &str)| line.trim_start().starts_with("//");
+    let above = lines[..item_line]
+        .iter()
+        .rev()
+        .take_while(|line| is_comment(line));
+    let within = lines[item_line..]
+        .iter()
+        .take_while(|line| is_comment(line));
+
+    above
+        .chain(within)
+        .find_map(|(line_offset, line)| line.find(doc_line).map(|i| line_offset + i))
+        .unwrap_or(span_start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let source = "model A {\n  id Int @id\n}\n";
+
+        assert_eq!(location(source, 0), (1, 1));
+        assert_eq!(location(source, 12), (2, 3));
+        assert_eq!(location(source, source.len()), (4, 1));
+    }
+
+    #[test]
+    fn test_find_doc_line() {
+        let text = "model A {\n  /// Docs\n  /// @prs.visiblity = private\n  id Int @id\n}";
+        let field = text.find("id Int").unwrap();
+
+        let offset = find_doc_line(text, field, "@prs.visiblity = private");
+        assert_eq!(&text[offset..offset + 14], "@prs.visiblity");
+        assert_eq!(find_doc_line(text, field, "@prs.missing"), field);
+    }
+
+    #[test]
+    fn test_annotation_errors() {
+        let errors = annotation_errors::<FieldAnnotation>(
+            "Docs\n@prs.visiblity = private\n@prs.visibility = hidden\n@prs.type = Vec<\n@prs.skip",
+        );
+        let lines = errors.iter().map(|(line, _)| *line).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "@prs.visiblity = private",
+                "@prs.visibility = hidden",
+                "@prs.type = Vec<"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let diagnostics = || {
+            vec![Diagnostic {
+                location: "schema.prisma:2:7".to_string(),
+                message: "Unknown annotation: visiblity = private".to_string(),
+            }]
+        };
+
+        assert_eq!(
+            report(diagnostics(), false).unwrap(),
+            vec!["schema.prisma:2:7: Unknown annotation: visiblity = private".to_string()]
+        );
+        assert!(report(diagnostics(), true).is_err());
+        assert!(report(vec![], true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_lenient_annotation() {
+        let annotation: FieldAnnotation = "@prs.visiblity = private\n@prs.skip".into();
+        assert!(annotation.skip, "valid lines are still parsed");
+    }
+}
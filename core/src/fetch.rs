@@ -0,0 +1,241 @@
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// The environment variable which enables offline mode, unless it is `0` or `false`
+pub const OFFLINE_ENV: &str = "PRISMA_RUST_SCHEMA_OFFLINE";
+
+/// How remote schemas are fetched
+#[derive(Debug)]
+pub struct FetchOptions {
+    /// Where fetched schemas are cached, keyed by URL
+    pub cache_dir: PathBuf,
+    /// Only use the cache
+    pub offline: bool,
+    /// The expected SHA-256 of the schema, as hex
+    pub sha256: Option<String>,
+}
+
+impl FetchOptions {
+    /// Caches under the `target` directory, and is offline with the `offline` import option, `PRISMA_RUST_SCHEMA_OFFLINE` or `CARGO_NET_OFFLINE`
+    pub fn new(manifest_dir: &Path, offline: Option<bool>, sha256: Option<String>) -> Self {
+        let target_dir = target_dir(manifest_dir);
+        let offline = offline.unwrap_or_else(|| {
+            [OFFLINE_ENV, "CARGO_NET_OFFLINE"]
+                .iter()
+                .any(|name| std::env::var(name).is_ok_and(|value| value != "0" && value != "false"))
+        });
+
+        FetchOptions {
+            cache_dir: target_dir.join("prisma-rust-schema"),
+            offline,
+            sha256,
+        }
+    }
+}
+
+/// The `target` directory of the build: `CARGO_TARGET_DIR`, the ancestor of `OUT_DIR` with Cargo's `CACHEDIR.TAG`
+/// (in a build script), or `target` in the workspace root, which is the nearest directory with a `[workspace]` manifest.
+/// Without a workspace, it is `target` in the manifest directory.
+fn target_dir(manifest_dir: &Path) -> PathBuf {
+    if let Ok(target_dir) = std::env::var("CARGO_TARGET_DIR") {
+        return PathBuf::from(target_dir);
+    }
+
+    if let Some(target_dir) = std::env::var_os("OUT_DIR").and_then(|out_dir| {
+        Path::new(&out_dir)
+            .ancestors()
+            .find(|dir| dir.join("CACHEDIR.TAG").is_file())
+            .map(Path::to_path_buf)
+    }) {
+        return target_dir;
+    }
+
+    let workspace_root = manifest_dir.ancestors().find(|dir| {
+        std::fs::read_to_string(dir.join("Cargo.toml"))
+            .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+    });
+    workspace_root.unwrap_or(manifest_dir).join("target")
+}
+
+/// Only `http://` and `https://` entries of `schema_paths` are fetched
+pub fn is_url(schema_path: &str) -> bool {
+    schema_path.starts_with("http://") || schema_path.starts_with("https://")
+}
+
+/// Fetches a remote schema, falling back to the cache when offline or when the request fails.
+///
+/// A pinned schema is read from the cache without a request, if the cached schema matches the pin.
+/// Falling back to the cache after a failed request adds a warning, as the cached schema can be stale.
+pub fn fetch_schema(
+    url: &str,
+    options: &FetchOptions,
+    warnings: &mut Vec<String>,
+) -> Result<String, String> {
+    let cache_path = options
+        .cache_dir
+        .join(format!("{}.prisma", sha256_hex(url)));
+    let cached = std::fs::read_to_string(&cache_path).ok();
+
+    match &cached {
+        Some(schema) if options.offline => {
+            check_sha256(schema, options)?;
+            return Ok(schema.clone());
+        }
+        // A pinned schema cannot change, so a matching cached schema is used as-is
+        Some(schema) if options.sha256.is_some() && check_sha256(schema, options).is_ok() => {
+            return Ok(schema.clone());
+        }
+        None if options.offline => {
+            return Err(format!(
+                "Unable to fetch {url} in offline mode: it is not cached in {}",
+                options.cache_dir.display()
+            ));
+        }
+        _ => {}
+    }
+
+    let schema = match request(url) {
+        Ok(schema) => schema,
+        Err(e) => match cached {
+            Some(schema) => {
+                warnings.push(format!(
+                    "Unable to fetch {url}, so the cached schema is used, which may be outdated: {e}"
+                ));
+                schema
+            }
+            None => return Err(format!("Unable to fetch {url}: {e}")),
+        },
+    };
+    check_sha256(&schema, options)?;
+
+    // Failing to cache only means the next build fetches again
+    if std::fs::create_dir_all(&options.cache_dir).is_ok() {
+        let _ = std::fs::write(&cache_path, &schema);
+    }
+
+    Ok(schema)
+}
+
+fn request(url: &str) -> Result<String, reqwest::Error> {
+    reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()?
+        .get(url)
+        .send()?
+        .error_for_status()?
+        .text()
+}
+
+fn check_sha256(schema: &str, options: &FetchOptions) -> Result<(), String> {
+    let Some(expected) = &options.sha256 else {
+        return Ok(());
+    };
+
+    let actual = sha256_hex(schema);
+    if !actual.eq_ignore_ascii_case(expected.trim()) {
+        return Err(format!(
+            "Schema does not match the `sha256` pin\nExpected: {expected}\nActual: {actual}"
+        ));
+    }
+    Ok(())
+}
+
+fn sha256_hex(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    const SCHEMA: &str = "model A {\n  id Int @id\n}\n";
+
+    /// Serves `body` to `requests` requests, and returns the URL
+    fn serve(body: &'static str, requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/schema.prisma", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut buffer = [0; 1024];
+                let _ = stream.read(&mut buffer);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        url
+    }
+
+    fn options(name: &str, offline: bool, sha256: Option<String>) -> FetchOptions {
+        let cache_dir = std::env::temp_dir()
+            .join("prisma-rust-schema-tests")
+            .join(format!("{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&cache_dir);
+        FetchOptions {
+            cache_dir,
+            offline,
+            sha256,
+        }
+    }
+
+    #[test]
+    fn test_is_url() {
+        assert!(is_url("https://example.com/schema.prisma"));
+        assert!(is_url("http://localhost:8080/schema.prisma"));
+        assert!(!is_url("./prisma/schema.prisma"));
+        assert!(!is_url("prisma/http.prisma"));
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let url = serve(SCHEMA, 1);
+        let mut options = options("cache", false, None);
+
+        assert_eq!(fetch_schema(&url, &options, &mut vec![]).unwrap(), SCHEMA);
+
+        // The server is gone, so this can only come from the cache
+        options.offline = true;
+        assert_eq!(fetch_schema(&url, &options, &mut vec![]).unwrap(), SCHEMA);
+    }
+
+    #[test]
+    fn test_stale_cache_warning() {
+        let url = serve(SCHEMA, 1);
+        let options = options("stale", false, None);
+
+        let mut warnings = vec![];
+        assert_eq!(fetch_schema(&url, &options, &mut warnings).unwrap(), SCHEMA);
+        assert!(warnings.is_empty());
+
+        // The server is gone, so the request fails and the cache is used
+        assert_eq!(fetch_schema(&url, &options, &mut warnings).unwrap(), SCHEMA);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("cached schema"), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn test_offline_without_cache() {
+        let options = options("offline", true, None);
+        let error =
+            fetch_schema("http://127.0.0.1:1/schema.prisma", &options, &mut vec![]).unwrap_err();
+        assert!(error.contains("offline mode"), "{error}");
+    }
+
+    #[test]
+    fn test_sha256_pin() {
+        let url = serve(SCHEMA, 2);
+
+        let pinned = options("pinned", false, Some(sha256_hex(SCHEMA).to_uppercase()));
+        assert_eq!(fetch_schema(&url, &pinned, &mut vec![]).unwrap(), SCHEMA);
+
+        let mismatch = options("mismatch", false, Some(sha256_hex("other")));
+        let error = fetch_schema(&url, &mismatch, &mut vec![]).unwrap_err();
+        assert!(error.contains("does not match the `sha256` pin"), "{error}");
+    }
+}
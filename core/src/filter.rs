@@ -0,0 +1,293 @@
+use psl::schema_ast::ast::{Top, WithName};
+use quote::{ToTokens, format_ident, quote};
+use syn::Ident;
+
+use crate::ImportOptions;
+use crate::annotation::Visibility;
+use crate::code::{StructField, derives_serde, handle_derive};
+use crate::default::take_default;
+use crate::transform::get_struct_name;
+
+/// The filter types shared by every `<Model>WhereInput` of an `import_types!` invocation.
+///
+/// They are generated by every invocation with `where_input = true`, so two such invocations in one module need different `prefix`es.
+///
+/// `T` is the type of the field, so a nullable field filters on `Option<T>`, and `equals: Some(None)` serializes as `null`.
+/// `Default` is implemented without requiring `T: Default`.
+pub fn handle_filters(import_options: &ImportOptions) -> proc_macro2::TokenStream {
+    let string_filter = get_struct_name("StringFilter".to_string(), import_options);
+    let scalar_filter = get_struct_name("ScalarFilter".to_string(), import_options);
+    let equality_filter = get_struct_name("EqualityFilter".to_string(), import_options);
+    let bool_filter = get_struct_name("BoolFilter".to_string(), import_options);
+    let derive = handle_filter_derive(import_options);
+
+    let serde = SerdeAttribute(derives_serde(&import_options.derive));
+    let optional = serde.attribute(quote! { default, skip_serializing_if = "Option::is_none" });
+    let in_ =
+        serde.attribute(quote! { rename = "in", default, skip_serializing_if = "Option::is_none" });
+    let not_in = serde
+        .attribute(quote! { rename = "notIn", default, skip_serializing_if = "Option::is_none" });
+    let starts_with = serde.attribute(
+        quote! { rename = "startsWith", default, skip_serializing_if = "Option::is_none" },
+    );
+    let ends_with = serde.attribute(
+        quote! { rename = "endsWith", default, skip_serializing_if = "Option::is_none" },
+    );
+
+    quote! {
+        /// Filters a `String` field, matching the Prisma Client `StringFilter`
+        #derive
+        pub struct #string_filter<T = String> {
+            #optional
+            pub equals: Option<T>,
+            #in_
+            pub in_: Option<Vec<T>>,
+            #not_in
+            pub not_in: Option<Vec<T>>,
+            #optional
+            pub lt: Option<String>,
+            #optional
+            pub lte: Option<String>,
+            #optional
+            pub gt: Option<String>,
+            #optional
+            pub gte: Option<String>,
+            #optional
+            pub contains: Option<String>,
+            #starts_with
+            pub starts_with: Option<String>,
+            #ends_with
+            pub ends_with: Option<String>,
+            #optional
+            pub not: Option<Box<#string_filter<T>>>,
+        }
+
+        /// Filters a numeric or date and time field, matching the Prisma Client `IntFilter`, `BigIntFilter`, `FloatFilter`, `DecimalFilter` and `DateTimeFilter`
+        #derive
+        pub struct #scalar_filter<T> {
+            #optional
+            pub equals: Option<T>,
+            #in_
+            pub in_: Option<Vec<T>>,
+            #not_in
+            pub not_in: Option<Vec<T>>,
+            #optional
+            pub lt: Option<T>,
+            #optional
+            pub lte: Option<T>,
+            #optional
+            pub gt: Option<T>,
+            #optional
+            pub gte: Option<T>,
+            #optional
+            pub not: Option<Box<#scalar_filter<T>>>,
+        }
+
+        /// Filters an enum or `Bytes` field, which can not be ordered, matching the Prisma Client `EnumFilter` and `BytesFilter`
+        #derive
+        pub struct #equality_filter<T> {
+            #optional
+            pub equals: Option<T>,
+            #in_
+            pub in_: Option<Vec<T>>,
+            #not_in
+            pub not_in: Option<Vec<T>>,
+            #optional
+            pub not: Option<Box<#equality_filter<T>>>,
+        }
+
+        /// Filters a `Boolean` field, matching the Prisma Client `BoolFilter`
+        #derive
+        pub struct #bool_filter<T = bool> {
+            #optional
+            pub equals: Option<T>,
+            #optional
+            pub not: Option<Box<#bool_filter<T>>>,
+        }
+
+        impl<T> Default for #string_filter<T> {
+            fn default() -> Self {
+                Self {
+                    equals: None,
+                    in_: None,
+                    not_in: None,
+                    lt: None,
+                    lte: None,
+                    gt: None,
+                    gte: None,
+                    contains: None,
+                    starts_with: None,
+                    ends_with: None,
+                    not: None,
+                }
+            }
+        }
+
+        impl<T> Default for #scalar_filter<T> {
+            fn default() -> Self {
+                Self {
+                    equals: None,
+                    in_: None,
+                    not_in: None,
+                    lt: None,
+                    lte: None,
+                    gt: None,
+                    gte: None,
+                    not: None,
+                }
+            }
+        }
+
+        impl<T> Default for #equality_filter<T> {
+            fn default() -> Self {
+                Self {
+                    equals: None,
+                    in_: None,
+                    not_in: None,
+                    not: None,
+                }
+            }
+        }
+
+        impl<T> Default for #bool_filter<T> {
+            fn default() -> Self {
+                Self {
+                    equals: None,
+                    not: None,
+                }
+            }
+        }
+    }
+}
+
+/// `#[serde(...)]` attributes, which are only in scope with a `Serialize` or `Deserialize` derive
+struct SerdeAttribute(bool);
+
+impl SerdeAttribute {
+    fn attribute(&self, arguments: proc_macro2::TokenStream) -> Option<proc_macro2::TokenStream> {
+        self.0.then(|| quote! { #[serde(#arguments)] })
+    }
+}
+
+/// `<Model>WhereInput`, with a filter for every scalar field and `AND`, `OR` and `NOT`.
+///
+/// List, `Json` and composite type fields, and fields with a `serde_as` adapter have no filter.
+pub fn handle_where_input(
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    struct_name: &Ident,
+    visibility: Visibility,
+    derive: Option<Vec<String>>,
+    fields: &[StructField],
+) -> proc_macro2::TokenStream {
+    let input_name = format_ident!("{}WhereInput", struct_name);
+    let serde = SerdeAttribute(derives_serde(&derive));
+
+    let filter_fields = fields
+        .iter()
+        .filter(|field| !field.is_relation && has_filter(schema, field))
+        .map(|field| {
+            let StructField {
+                name, visibility, ..
+            } = field;
+            let type_name = match &field.patch {
+                Some(patch) => {
+                    let ty = &patch.ty;
+                    quote! { #ty }
+                }
+                None if field.type_override && field.field.arity.is_optional() => {
+                    let type_name = &field.type_name;
+                    quote! { Option<#type_name> }
+                }
+                None => field.type_name.clone(),
+            };
+            let filter = get_struct_name(filter_name(schema, field).to_string(), import_options);
+
+            // Prisma Client queries use the Prisma field name, not the database name
+            let prisma_name = field.field.name();
+            let serde_rename = if *name != prisma_name {
+                Some(quote! { rename = #prisma_name, })
+            } else {
+                None
+            };
+
+            let attribute = serde.attribute(
+                quote! { #serde_rename default, skip_serializing_if = "Option::is_none" },
+            );
+
+            quote! {
+                #attribute
+                #visibility #name: Option<#filter<#type_name>>,
+            }
+        });
+
+    let (derive, _) = take_default(derive);
+    let derive = handle_derive(Some(
+        derive
+            .into_iter()
+            .flatten()
+            .chain(["Default".to_string()])
+            .collect(),
+    ));
+
+    let and =
+        serde.attribute(quote! { rename = "AND", default, skip_serializing_if = "Vec::is_empty" });
+    let or =
+        serde.attribute(quote! { rename = "OR", default, skip_serializing_if = "Vec::is_empty" });
+    let not =
+        serde.attribute(quote! { rename = "NOT", default, skip_serializing_if = "Vec::is_empty" });
+
+    quote! {
+        #derive
+        #visibility struct #input_name {
+            #and
+            #visibility and: Vec<#input_name>,
+            #or
+            #visibility or: Vec<#input_name>,
+            #not
+            #visibility not: Vec<#input_name>,
+            #(#filter_fields)*
+        }
+    }
+}
+
+/// The filter of a field, by its Prisma type, as Prisma only allows ordering comparisons on numbers and dates
+fn filter_name(schema: &Vec<Top>, field: &StructField) -> &'static str {
+    let type_name = field.field.field_type.name();
+    match type_name {
+        "String" if !field.type_override => "StringFilter",
+        "Boolean" => "BoolFilter",
+        "Bytes" => "EqualityFilter",
+        _ if is_enum(schema, type_name) => "EqualityFilter",
+        _ => "ScalarFilter",
+    }
+}
+
+fn is_enum(schema: &Vec<Top>, type_name: &str) -> bool {
+    schema.iter().any(|top| match top {
+        Top::Enum(enum_type) => enum_type.name() == type_name,
+        _ => false,
+    })
+}
+
+fn has_filter(schema: &Vec<Top>, field: &StructField) -> bool {
+    if field.field.arity.is_list() || (field.serde_as.is_some() && field.patch.is_none()) {
+        return false;
+    }
+
+    let type_name = field.field.field_type.name();
+    if type_name == "Json" {
+        return false;
+    }
+
+    !schema.iter().any(|top| match top {
+        Top::CompositeType(composite_type) => composite_type.name() == type_name,
+        _ => false,
+    })
+}
+
+/// The `derive` import option, without `Default`
+fn handle_filter_derive(import_options: &ImportOptions) -> impl ToTokens {
+    let (derive, _) = take_default(import_options.derive.clone());
+    handle_derive(derive)
+}
@@ -0,0 +1,281 @@
+use psl::schema_ast::ast::WithName;
+use quote::{ToTokens, format_ident, quote};
+use syn::Ident;
+
+use crate::annotation::Visibility;
+use crate::code::{StructField, derives, derives_serde, handle_derive};
+
+/// `<Model>CreateInput`, mirroring Prisma Client's `<Model>CreateInput`.
+///
+/// Fields generated by the database (`@default(...)`, `@updatedAt`) are optional, and relation fields are excluded.
+/// Converting a model into its create input is always possible. The reverse is only generated when no field is generated by the database,
+/// and no relation field has a `@prs.type` override.
+pub fn handle_create_input(
+    struct_name: &Ident,
+    visibility: Visibility,
+    derive: Option<Vec<String>>,
+    fields: &[StructField],
+) -> proc_macro2::TokenStream {
+    let input_name = format_ident!("{}CreateInput", struct_name);
+    let relations = fields
+        .iter()
+        .filter(|field| field.is_relation)
+        .collect::<Vec<_>>();
+    let relation_names = relations.iter().map(|field| &field.name);
+    let fields = fields
+        .iter()
+        .filter(|field| !field.is_relation)
+        .collect::<Vec<_>>();
+
+    let input_fields = fields
+        .iter()
+        .map(|field| InputField::new(field, is_generated(field), &input_name, &derive))
+        .collect::<Vec<_>>();
+
+    let serde_as = handle_input_serde_as(&input_fields);
+    let deserialize_some = handle_deserialize_some(&input_name, &input_fields);
+    let derive = handle_derive(derive);
+
+    let from_model = fields.iter().map(|field| {
+        let name = &field.name;
+        if is_generated(field) {
+            quote! { #name: Some(value.#name), }
+        } else {
+            quote! { #name: value.#name, }
+        }
+    });
+
+    // A relation with a `@prs.type` override can not be left empty with `None`
+    let from_input = if fields.iter().any(|field| is_generated(field))
+        || relations.iter().any(|field| field.type_override)
+    {
+        None
+    } else {
+        let field_names = fields.iter().map(|field| &field.name);
+        Some(quote! {
+            impl From<#input_name> for #struct_name {
+                fn from(value: #input_name) -> Self {
+                    Self {
+                        #(#field_names: value.#field_names,)*
+                        #(#relation_names: None,)*
+                    }
+                }
+            }
+        })
+    };
+
+    quote! {
+        #serde_as
+        #derive
+        #visibility struct #input_name {
+            #(#input_fields)*
+        }
+
+        impl From<#struct_name> for #input_name {
+            fn from(value: #struct_name) -> Self {
+                Self {
+                    #(#from_model)*
+                }
+            }
+        }
+
+        #from_input
+        #deserialize_some
+    }
+}
+
+/// `<Model>UpdateInput`, where every scalar field outside of the primary key is optional.
+///
+/// A nullable field becomes `Option<Option<T>>`: `None` leaves the field unchanged, and `Some(None)` sets it to `null`.
+/// `Default` is always derived, so an update can be written as `UserUpdateInput { name: Some(..), ..Default::default() }`.
+pub fn handle_update_input(
+    struct_name: &Ident,
+    visibility: Visibility,
+    derive: Option<Vec<String>>,
+    fields: &[StructField],
+    id_fields: &[&str],
+) -> proc_macro2::TokenStream {
+    let input_name = format_ident!("{}UpdateInput", struct_name);
+
+    let input_fields = fields
+        .iter()
+        .filter(|field| !field.is_relation && !id_fields.contains(&field.field.name()))
+        .map(|field| InputField::new(field, true, &input_name, &derive))
+        .collect::<Vec<_>>();
+
+    let serde_as = handle_input_serde_as(&input_fields);
+    let deserialize_some = handle_deserialize_some(&input_name, &input_fields);
+    let derive = handle_derive(Some(
+        derive
+            .into_iter()
+            .flatten()
+            .chain(["Default".to_string()])
+            .collect(),
+    ));
+
+    quote! {
+        #serde_as
+        #derive
+        #visibility struct #input_name {
+            #(#input_fields)*
+        }
+
+        #deserialize_some
+    }
+}
+
+/// Whether the value of a field can be generated by the database
+fn is_generated(field: &StructField) -> bool {
+    field
+        .field
+        .attributes
+        .iter()
+        .any(|a| a.name() == "default" || a.name() == "updatedAt")
+}
+
+fn handle_input_serde_as(fields: &[InputField]) -> Option<proc_macro2::TokenStream> {
+    if fields
+        .iter()
+        .any(|field| field.field.serde_as.is_some() && field.field.patch.is_none())
+    {
+        Some(quote! { #[serde_with::serde_as] })
+    } else {
+        None
+    }
+}
+
+/// Without `deserialize_with`, serde deserializes `null` into `None` instead of `Some(None)` for an `Option<Option<T>>`.
+fn handle_deserialize_some(
+    input_name: &Ident,
+    fields: &[InputField],
+) -> Option<proc_macro2::TokenStream> {
+    if !fields.iter().any(|field| field.deserialize_some.is_some()) {
+        return None;
+    }
+
+    Some(quote! {
+        impl #input_name {
+            fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+            where
+                T: serde::Deserialize<'de>,
+                D: serde::Deserializer<'de>,
+            {
+                serde::Deserialize::deserialize(deserializer).map(Some)
+            }
+        }
+    })
+}
+
+/// A field of an input struct. Optional fields are wrapped in `Option` and skipped when `None`.
+struct InputField<'a> {
+    field: &'a StructField<'a>,
+    optional: bool,
+    /// The path to the `deserialize_some` function, for an optional field which is also nullable
+    deserialize_some: Option<String>,
+    /// Whether the input derives `Serialize` or `Deserialize`, so `#[serde]` attributes can be used
+    serde: bool,
+}
+
+impl<'a> InputField<'a> {
+    fn new(
+        field: &'a StructField<'a>,
+        optional: bool,
+        input_name: &Ident,
+        derive: &Option<Vec<String>>,
+    ) -> Self {
+        let nullable = field.field.arity.is_optional()
+            && !field.type_override
+            && field.patch.is_none()
+            && field.serde_as.is_none();
+        let deserialize_some = if optional && nullable && derives(derive, "Deserialize") {
+            Some(format!("{input_name}::deserialize_some"))
+        } else {
+            None
+        };
+
+        InputField {
+            field,
+            optional,
+            deserialize_some,
+            serde: derives_serde(derive),
+        }
+    }
+}
+
+impl ToTokens for InputField<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let StructField {
+            name,
+            type_name,
+            attributes,
+            visibility,
+            serde_as,
+            patch,
+            ..
+        } = self.field;
+
+        let (attributes, visibility, type_name) = match patch {
+            Some(patch) => {
+                let attrs = &patch.attrs;
+                let vis = &patch.vis;
+                let ty = &patch.ty;
+                (quote! { #(#attrs)* }, quote! { #vis }, quote! { #ty })
+            }
+            None => {
+                let serde_as = serde_as.as_ref().map(|adapter| {
+                    let adapter = if self.optional {
+                        format!("Option<{adapter}>")
+                    } else {
+                        adapter.to_string()
+                    };
+                    quote! { #[serde_as(as = #adapter)] }
+                });
+                (
+                    quote! { #attributes #serde_as },
+                    quote! { #visibility },
+                    type_name.clone(),
+                )
+            }
+        };
+
+        // Without a serde derive, the `serde` attribute is not in scope
+        let skip_none = if self.serde {
+            Some(quote! { #[serde(default, skip_serializing_if = "Option::is_none")] })
+        } else {
+            None
+        };
+
+        if let Some(deserialize_some) = &self.deserialize_some {
+            quote! {
+                #attributes
+                #[serde(
+                    default,
+                    skip_serializing_if = "Option::is_none",
+                    deserialize_with = #deserialize_some
+                )]
+                #visibility #name: Option<#type_name>,
+            }
+            .to_tokens(tokens);
+        } else if self.optional {
+            quote! {
+                #attributes
+                #skip_none
+                #visibility #name: Option<#type_name>,
+            }
+            .to_tokens(tokens);
+        } else if patch.is_none() && self.field.field.arity.is_optional() {
+            quote! {
+                #attributes
+                #skip_none
+                #visibility #name: #type_name,
+            }
+            .to_tokens(tokens);
+        } else {
+            quote! {
+                #attributes
+                #visibility #name: #type_name,
+            }
+            .to_tokens(tokens);
+        }
+    }
+}
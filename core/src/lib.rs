@@ -0,0 +1,658 @@
+//! # Prisma Rust Schema Core
+//!
+//! Generates Rust types from Prisma schema files. Used by the `import_types!` macro of
+//! `prisma-rust-schema`, and usable directly from a build script or other tooling.
+
+use annotation::{EnumAnnotation, EnumValueAnnotation, ModelAnnotation, TypeAnnotation};
+use code::{
+    apply_patch, extract_docs, get_id_fields, get_map_attribute, handle_derive, handle_field_enum,
+    handle_fields, handle_serde_as,
+};
+use config::{find_generator, generator_config};
+use default::{derives_default, handle_default_impl, take_default};
+use diagnostic::{report, span_error, validate_annotations};
+use fetch::{FetchOptions, fetch_schema, is_url};
+use filter::{handle_filters, handle_where_input};
+use input::{handle_create_input, handle_update_input};
+use load::resolve_local;
+use newtype::{apply_id_newtypes, handle_id_newtype};
+use psl::{
+    SourceFile, parse_schema_multi,
+    schema_ast::ast::{Top, WithDocumentation, WithName, WithSpan},
+};
+use quote::{format_ident, quote};
+use std::path::{Path, PathBuf};
+use syn::ItemStruct;
+use unique::handle_unique_keys;
+
+use crate::transform::{Datasource, TypeMapping, get_enum_name, get_struct_name};
+
+pub use config::ConfigValue;
+
+mod annotation;
+mod code;
+mod config;
+mod default;
+mod diagnostic;
+mod fetch;
+mod filter;
+mod input;
+mod load;
+mod newtype;
+mod transform;
+mod unique;
+
+/// The options of a [`Generator`], which are the options of the `import_types!` macro
+#[derive(Debug, Default, Clone)]
+pub struct ImportOptions {
+    /// The schema files, directories, globs or URLs to generate types from
+    pub schema_paths: Vec<String>,
+    /// The paths of the derive macros added to every generated type, e.g. `"Debug"`
+    pub derive: Option<Vec<String>>,
+    /// The names of the models, enums and composite types to generate. Defaults to all
+    pub include: Option<Vec<String>>,
+    /// The prefix of every generated type name
+    pub prefix: Option<String>,
+    /// Structs whose fields overwrite the generated fields of the struct with the same name
+    pub patch: Option<Vec<ItemStruct>>,
+    /// Whether to include relation fields as `Option<Box<T>>` and `Option<Vec<T>>`
+    pub relations: Option<bool>,
+    /// Whether to generate a `<Model>CreateInput` struct for every model
+    pub create_input: Option<bool>,
+    /// Whether to generate a `<Model>UpdateInput` struct for every model
+    pub update_input: Option<bool>,
+    /// Whether to generate a `<Model>WhereInput` struct for every model, and the filter types it uses
+    pub where_input: Option<bool>,
+    /// Whether to generate a `<Struct>Field` enum for every model and composite type
+    pub field_enums: Option<bool>,
+    /// Whether to generate a `<Model>PrimaryKey` type and a `<Model>WhereUniqueInput` enum for every model
+    pub unique_keys: Option<bool>,
+    /// Whether to generate an ID newtype for the `@id` field of every model
+    pub id_newtypes: Option<bool>,
+    /// The expected SHA-256 of the remote schema, as hex
+    pub sha256: Option<String>,
+    /// Whether to only use cached remote schemas. Defaults to the `PRISMA_RUST_SCHEMA_OFFLINE` environment variable
+    pub offline: Option<bool>,
+    /// Whether invalid `@prs.` annotations are errors (default), or warnings which are ignored
+    pub strict: Option<bool>,
+    /// `"chrono" | "time" | "jiff" | "bson" | "string"`
+    pub datetime: Option<String>,
+    /// `"bson" | "string"`
+    pub object_id: Option<String>,
+    /// `"serde_json"` or the path to a custom type
+    pub json: Option<String>,
+    /// `"rust_decimal" | "bigdecimal" | "string"`
+    pub decimal: Option<String>,
+    /// `"vec" | "bytes" | "bson"`
+    pub bytes: Option<String>,
+}
+
+/// Generates Rust types from Prisma schema files
+///
+/// ```no_run
+/// use prisma_rust_schema_core::{Generator, ImportOptions};
+///
+/// let code = Generator::new(ImportOptions {
+///     derive: Some(vec!["Debug".to_string(), "Clone".to_string()]),
+///     ..Default::default()
+/// })
+/// .add_schema("prisma/schema.prisma")
+/// .generate_string()
+/// .unwrap();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Generator {
+    options: ImportOptions,
+    sources: Vec<(String, String)>,
+    base_dir: Option<PathBuf>,
+    track_files: bool,
+}
+
+impl Generator {
+    pub fn new(options: ImportOptions) -> Self {
+        Self {
+            options,
+            sources: Vec::new(),
+            base_dir: None,
+            track_files: false,
+        }
+    }
+
+    /// Adds a schema file, directory, glob or URL to the `schema_paths` option
+    pub fn add_schema(mut self, schema_path: impl Into<String>) -> Self {
+        self.options.schema_paths.push(schema_path.into());
+        self
+    }
+
+    /// Adds a schema from its source code. The name is used in error messages
+    pub fn add_source(mut self, name: impl Into<String>, source: impl Into<String>) -> Self {
+        self.sources.push((name.into(), source.into()));
+        self
+    }
+
+    /// The directory relative schema paths are resolved from, and the remote schema cache is kept in.
+    /// Defaults to `CARGO_MANIFEST_DIR`, or the current directory
+    pub fn base_dir(mut self, base_dir: impl Into<PathBuf>) -> Self {
+        self.base_dir = Some(base_dir.into());
+        self
+    }
+
+    /// Whether the generated code includes the local schema files with `include_bytes!`,
+    /// so Cargo rebuilds the crate when they change. Only useful in a proc-macro.
+    ///
+    /// Directories and globs are not tracked, so a file added to one is only loaded once the crate is rebuilt for another reason
+    pub fn track_files(mut self, track_files: bool) -> Self {
+        self.track_files = track_files;
+        self
+    }
+
+    /// Generates the types as a token stream.
+    /// With `strict = false`, invalid annotations are ignored, see [`Generator::generate_output`] for the warnings
+    pub fn generate(&self) -> syn::Result<proc_macro2::TokenStream> {
+        Ok(self.generate_output()?.tokens)
+    }
+
+    /// Generates the types as formatted Rust source code.
+    /// With `strict = false`, invalid annotations are ignored, see [`Generator::generate_output`] for the warnings
+    pub fn generate_string(&self) -> syn::Result<String> {
+        self.generate_output()?.to_source()
+    }
+
+    /// Generates the types, with the warnings about invalid annotations when `strict = false`,
+    /// and about remote schemas which could only be read from the cache
+    pub fn generate_output(&self) -> syn::Result<Generated> {
+        let dir = match &self.base_dir {
+            Some(dir) => dir.clone(),
+            None => std::env::var("CARGO_MANIFEST_DIR").map_or_else(
+                |_| std::env::current_dir().expect("current dir to be determined"),
+                PathBuf::from,
+            ),
+        };
+
+        generate(&self.options, &self.sources, &dir, self.track_files)
+    }
+}
+
+/// The output of a [`Generator`]
+#[derive(Debug, Clone)]
+pub struct Generated {
+    /// The generated types
+    pub tokens: proc_macro2::TokenStream,
+    /// The invalid annotations which were ignored with `strict = false`, as `file:line:column: message`,
+    /// and the remote schemas which could not be fetched, so their cached schema was used
+    pub warnings: Vec<String>,
+}
+
+impl Generated {
+    /// The generated types as formatted Rust source code
+    pub fn to_source(&self) -> syn::Result<String> {
+        let file = syn::parse2::<syn::File>(self.tokens.clone())?;
+        Ok(prettyplease::unparse(&file))
+    }
+}
+
+fn generate(
+    import_options: &ImportOptions,
+    sources: &[(String, String)],
+    dir: &Path,
+    track_files: bool,
+) -> syn::Result<Generated> {
+    // Collect all schemas from the provided paths
+    let mut files: Vec<(String, SourceFile)> = sources
+        .iter()
+        .map(|(name, source)| (name.clone(), SourceFile::from(source.clone())))
+        .collect();
+    let mut local_paths = Vec::new();
+    let remote_count = import_options
+        .schema_paths
+        .iter()
+        .filter(|schema_path| is_url(schema_path))
+        .count();
+    if import_options.sha256.is_some() && remote_count != 1 {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!(
+                "The `sha256` option pins a single remote schema, but `schema_paths` has {remote_count}"
+            ),
+        ));
+    }
+    let fetch_options =
+        FetchOptions::new(dir, import_options.offline, import_options.sha256.clone());
+    let mut warnings = Vec::new();
+
+    for schema_path in &import_options.schema_paths {
+        if is_url(schema_path) {
+            let schema = fetch_schema(schema_path, &fetch_options, &mut warnings)
+                .map_err(|e| syn::Error::new_spanned(schema_path, e))?;
+            files.push((schema_path.clone(), SourceFile::from(schema)));
+            continue;
+        }
+
+        for (name, path) in resolve_local(dir, schema_path)? {
+            // A file can be matched by more than one entry, e.g. a directory and a glob
+            if local_paths.contains(&path) {
+                continue;
+            }
+
+            let schema = std::fs::read_to_string(&path)
+                .map_err(|e| syn::Error::new_spanned(schema_path, e.to_string()))?;
+            files.push((name, SourceFile::from(schema)));
+            local_paths.push(path);
+        }
+    }
+
+    // Each file is parsed separately, so psl renders its errors with the file name, line, column and a snippet
+    let validated_schema = parse_schema_multi(&files)
+        .map_err(|e| syn::Error::new(proc_macro2::Span::call_site(), e))?;
+
+    let db = validated_schema.db;
+
+    // Combine all AST tops from all schemas
+    let tops: Vec<_> = db.iter_asts().flat_map(|ast| ast.tops.clone()).collect();
+
+    // The schema's own `generator` block supplies the options which are not set explicitly
+    let import_options = &match find_generator(&tops) {
+        Some(generator) => {
+            let defaults = ImportOptions::from_config(generator_config(generator))
+                .map_err(|e| span_error(&db, generator.span(), e))?;
+            import_options.clone().or(defaults)
+        }
+        None => import_options.clone(),
+    };
+
+    warnings.extend(report(
+        validate_annotations(&tops, &db),
+        import_options.strict.unwrap_or(true),
+    )?);
+
+    // Type mapping defaults depend on the connector of this schema
+    let type_mapping = TypeMapping::new(import_options, Datasource::from_tops(&tops))?;
+
+    // let mut output_token_stream = TokenStream::new();
+    let mut output_tokens = quote! {};
+
+    if import_options.where_input.unwrap_or(false) {
+        output_tokens.extend(handle_filters(import_options));
+    }
+
+    // Shared by the views of this invocation, like the filter types
+    let read_only_trait = get_struct_name("ReadOnly".to_string(), import_options);
+    if tops
+        .iter()
+        .any(|top| matches!(top, Top::Model(model) if model.is_view()))
+    {
+        output_tokens.extend(quote! {
+            /// Implemented by the structs of Prisma `view` blocks, which can not be written
+            pub trait #read_only_trait {}
+        });
+    }
+
+    for top in &tops {
+        match top {
+            Top::CompositeType(composite_type) => {
+                let name = composite_type.name().to_string();
+                if import_options.include.is_some() {
+                    let include = import_options.include.as_ref().expect("UNREACHABLE");
+                    if !include.contains(&name) {
+                        continue;
+                    }
+                }
+
+                let TypeAnnotation {
+                    skip,
+                    rename,
+                    visibility,
+                    derive,
+                    type_,
+                } = match composite_type.documentation() {
+                    Some(d) => d.into(),
+                    None => TypeAnnotation::default(),
+                };
+                if skip {
+                    continue;
+                }
+
+                let derive = derive.or(import_options.derive.clone());
+
+                // Reported by `validate_annotations`
+                if type_.is_some() {
+                    continue;
+                }
+
+                let struct_name = get_struct_name(rename.unwrap_or(name), import_options);
+
+                let documentation = extract_docs(composite_type.documentation().clone());
+                let mut fields = composite_type
+                    .iter_fields()
+                    .filter_map(|(_field_id, field)| {
+                        handle_fields(&tops, import_options, &type_mapping, &derive, field)
+                    })
+                    .collect::<Vec<_>>();
+                apply_patch(&mut fields, import_options, &struct_name);
+
+                let serde_as = handle_serde_as(&fields);
+                let (derive, default_impl) = take_default(derive);
+                let derive = handle_derive(derive);
+
+                let s = quote! {
+                    #documentation
+                    #serde_as
+                    #derive
+                    #visibility struct #struct_name {
+                        #(#fields)*
+                    }
+                };
+
+                output_tokens.extend(s);
+
+                if default_impl {
+                    output_tokens.extend(handle_default_impl(
+                        &db,
+                        &tops,
+                        import_options,
+                        &type_mapping,
+                        &struct_name,
+                        &fields,
+                    )?);
+                }
+
+                if import_options.field_enums.unwrap_or(false) {
+                    output_tokens.extend(handle_field_enum(&struct_name, visibility, &fields));
+                }
+            }
+            Top::Enum(enum_type) => {
+                let name = enum_type.name().to_string();
+                if import_options.include.is_some() {
+                    let include = import_options.include.as_ref().expect("UNREACHABLE");
+                    if !include.contains(&name) {
+                        continue;
+                    }
+                }
+
+                let EnumAnnotation {
+                    skip,
+                    rename,
+                    visibility,
+                    derive,
+                } = match enum_type.documentation() {
+                    Some(d) => d.into(),
+                    None => EnumAnnotation::default(),
+                };
+                if skip {
+                    continue;
+                }
+
+                let derive = derive.or(import_options.derive.clone());
+
+                let enum_name = get_enum_name(rename.unwrap_or(name), import_options);
+
+                let value_annotations = enum_type
+                    .values
+                    .iter()
+                    .map(|enum_value| match enum_value.documentation() {
+                        Some(d) => d.into(),
+                        None => EnumValueAnnotation::default(),
+                    })
+                    .collect::<Vec<_>>();
+
+                // When deriving `Default`, `#[default]` goes on the `@prs.default` value, otherwise the first value
+                let default_value = if derives_default(&derive) {
+                    let mut values = enum_type
+                        .values
+                        .iter()
+                        .zip(&value_annotations)
+                        .filter(|(_, annotation)| !annotation.skip);
+                    let default_value = values
+                        .clone()
+                        .find(|(_, annotation)| annotation.default)
+                        .or_else(|| values.next())
+                        .map(|(enum_value, _)| enum_value.name());
+                    if default_value.is_none() {
+                        return Err(span_error(
+                            &db,
+                            enum_type.span(),
+                            format!(
+                                "`{}` derives `Default`, but every value is skipped with `@prs.skip`",
+                                enum_type.name()
+                            ),
+                        ));
+                    }
+                    default_value
+                } else {
+                    None
+                };
+
+                let documentation = extract_docs(enum_type.documentation().clone());
+                let enum_values = enum_type.values.iter().zip(value_annotations).filter_map(
+                    |(enum_value, annotation)| {
+                        let EnumValueAnnotation { skip, rename, .. } = annotation;
+
+                        if skip {
+                            return None;
+                        }
+
+                        let default_attribute = if default_value == Some(enum_value.name()) {
+                            Some(quote! { #[default] })
+                        } else {
+                            None
+                        };
+
+                        // The database value (`@map`) takes precedence over the Prisma name
+                        let serde_rename =
+                            if let Some(db_name) = get_map_attribute(&enum_value.attributes) {
+                                let s = quote! {
+                                    #[serde(rename = #db_name)]
+                                };
+                                Some(s)
+                            } else if let Some(_changed_name) = &rename {
+                                let original_name = enum_value.name();
+                                let s = quote! {
+                                    #[serde(rename = #original_name)]
+                                };
+                                Some(s)
+                            } else {
+                                None
+                            };
+
+                        let name = match rename {
+                            Some(name) => name,
+                            None => enum_value.name().to_string(),
+                        };
+                        let name = format_ident!("{}", name);
+
+                        let documentation = extract_docs(enum_value.documentation().clone());
+
+                        return Some(quote! {
+                            #documentation
+                            #default_attribute
+                            #serde_rename
+                            #name,
+                        });
+                    },
+                );
+
+                let derive = handle_derive(derive);
+
+                let s = quote! {
+                    #documentation
+                    #derive
+                    #visibility enum #enum_name {
+                        #(#enum_values)*
+                    }
+                };
+                output_tokens.extend(s);
+
+                let prisma_name = enum_type.name();
+                let db_name = get_map_attribute(&enum_type.attributes).unwrap_or(prisma_name);
+                output_tokens.extend(quote! {
+                    impl #enum_name {
+                        /// The name of the Prisma enum
+                        #visibility const ENUM: &'static str = #prisma_name;
+                        /// The name of the enum in the database (`@@map`), or the Prisma enum name
+                        #visibility const DB_NAME: &'static str = #db_name;
+                    }
+                });
+            }
+            Top::Model(model) => {
+                let name = model.name().to_string();
+                if import_options.include.is_some() {
+                    let include = import_options.include.as_ref().expect("UNREACHABLE");
+                    if !include.contains(&name) {
+                        continue;
+                    }
+                }
+
+                let ModelAnnotation {
+                    skip,
+                    rename,
+                    visibility,
+                    derive,
+                } = match model.documentation() {
+                    Some(d) => d.into(),
+                    None => ModelAnnotation::default(),
+                };
+                if skip {
+                    continue;
+                }
+
+                let derive = derive.or(import_options.derive.clone());
+
+                let struct_name = get_struct_name(rename.unwrap_or(name), import_options);
+                let documentation = extract_docs(model.documentation().clone());
+                let mut fields = model
+                    .iter_fields()
+                    .filter_map(|(_field_id, field)| {
+                        handle_fields(&tops, import_options, &type_mapping, &derive, field)
+                    })
+                    .collect::<Vec<_>>();
+                let id_newtype = apply_id_newtypes(&tops, import_options, model, &mut fields);
+                apply_patch(&mut fields, import_options, &struct_name);
+
+                let serde_as = handle_serde_as(&fields);
+                let (derive, default_impl) = take_default(derive);
+                let input_derive = derive.clone();
+                let derive = handle_derive(derive);
+
+                let s = quote! {
+                    #documentation
+                    #serde_as
+                    #derive
+                    #visibility struct #struct_name {
+                        #(#fields)*
+                    }
+                };
+
+                output_tokens.extend(s);
+
+                if default_impl {
+                    output_tokens.extend(handle_default_impl(
+                        &db,
+                        &tops,
+                        import_options,
+                        &type_mapping,
+                        &struct_name,
+                        &fields,
+                    )?);
+                }
+
+                if import_options.field_enums.unwrap_or(false) {
+                    output_tokens.extend(handle_field_enum(&struct_name, visibility, &fields));
+                }
+
+                if let Some((newtype, inner)) = &id_newtype {
+                    output_tokens.extend(handle_id_newtype(
+                        newtype,
+                        inner,
+                        visibility,
+                        input_derive.clone(),
+                        default_impl,
+                    ));
+                }
+
+                // Views can only be read, so they have no inputs to write them
+                let read_only = model.is_view();
+
+                if import_options.create_input.unwrap_or(false) && !read_only {
+                    output_tokens.extend(handle_create_input(
+                        &struct_name,
+                        visibility,
+                        input_derive.clone(),
+                        &fields,
+                    ));
+                }
+
+                if import_options.update_input.unwrap_or(false) && !read_only {
+                    output_tokens.extend(handle_update_input(
+                        &struct_name,
+                        visibility,
+                        input_derive.clone(),
+                        &fields,
+                        &get_id_fields(model),
+                    ));
+                }
+
+                if import_options.where_input.unwrap_or(false) {
+                    output_tokens.extend(handle_where_input(
+                        &tops,
+                        import_options,
+                        &struct_name,
+                        visibility,
+                        input_derive.clone(),
+                        &fields,
+                    ));
+                }
+
+                if import_options.unique_keys.unwrap_or(false) {
+                    output_tokens.extend(handle_unique_keys(
+                        &tops,
+                        import_options,
+                        model,
+                        &struct_name,
+                        visibility,
+                        input_derive,
+                        &fields,
+                    ));
+                }
+
+                let prisma_name = model.name();
+                let table_name = get_map_attribute(&model.attributes).unwrap_or(prisma_name);
+                output_tokens.extend(quote! {
+                    impl #struct_name {
+                        /// The name of the Prisma model or view
+                        #visibility const MODEL: &'static str = #prisma_name;
+                        /// The name of the database table, view or collection (`@@map`), or the Prisma model name
+                        #visibility const TABLE: &'static str = #table_name;
+                        /// Whether this is a Prisma `view`, which can not be written
+                        #visibility const READ_ONLY: bool = #read_only;
+                    }
+                });
+                if read_only {
+                    output_tokens.extend(quote! {
+                        impl #read_only_trait for #struct_name {}
+                    });
+                }
+            }
+            _ => {
+                // Skip
+                continue;
+            }
+        }
+    }
+
+    // Cargo only rebuilds when a file read by the macro changes if the output depends on it.
+    // `include_bytes!` can not depend on a directory, so new files of a directory or glob are not noticed
+    if track_files {
+        let dependencies = local_paths.iter().map(|path| {
+            let path = path.to_string_lossy();
+            quote! {
+                const _: &[u8] = include_bytes!(#path);
+            }
+        });
+        output_tokens.extend(quote! { #(#dependencies)* });
+    }
+
+    Ok(Generated {
+        tokens: output_tokens,
+        warnings,
+    })
+}
@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+
+/// The local schema files of a `schema_paths` entry, in sorted order.
+///
+/// An entry is a file, a directory of `.prisma` files (e.g. a `prismaSchemaFolder`), or a glob pattern.
+/// Each file is named by its path relative to the manifest directory, for diagnostics.
+/// The paths are canonical, so a file reached through `..` or a symlink is the same path as the file itself.
+pub fn resolve_local(dir: &Path, schema_path: &String) -> syn::Result<Vec<(String, PathBuf)>> {
+    let is_glob = schema_path.contains(['*', '?', '[']);
+    let path = dir.join(schema_path);
+
+    if !is_glob {
+        if !path.exists() {
+            return Err(syn::Error::new_spanned(
+                schema_path,
+                format!("Schema file not found: {}", path.display()),
+            ));
+        }
+        if !path.is_dir() {
+            return Ok(vec![(
+                schema_path.clone(),
+                canonicalize(schema_path, &path)?,
+            )]);
+        }
+    }
+
+    // A directory includes every `.prisma` file in it, including in subdirectories
+    let pattern = if is_glob {
+        path
+    } else {
+        path.join("**/*.prisma")
+    };
+
+    let mut files = glob::glob(&pattern.to_string_lossy())
+        .map_err(|e| {
+            syn::Error::new_spanned(schema_path, format!("Invalid schema path pattern: {e}"))
+        })?
+        .map(|entry| {
+            entry
+                .map_err(|e| syn::Error::new_spanned(schema_path, e.to_string()))
+                .map(|path| {
+                    // Name the file relative to the manifest directory, like the entry
+                    let name = path
+                        .strip_prefix(dir)
+                        .unwrap_or(&path)
+                        .display()
+                        .to_string();
+                    (name, path)
+                })
+        })
+        .filter(|entry| match entry {
+            Ok((_, path)) => path.is_file(),
+            Err(_) => true,
+        })
+        .map(|entry| {
+            entry.and_then(|(name, path)| canonicalize(schema_path, &path).map(|path| (name, path)))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    if files.is_empty() {
+        return Err(syn::Error::new_spanned(
+            schema_path,
+            format!("No schema files found: {}", pattern.display()),
+        ));
+    }
+
+    files.sort_by(|(_, a), (_, b)| a.cmp(b));
+    Ok(files)
+}
+
+fn canonicalize(schema_path: &String, path: &Path) -> syn::Result<PathBuf> {
+    std::fs::canonicalize(path)
+        .map_err(|e| syn::Error::new_spanned(schema_path, format!("{}: {e}", path.display())))
+}
@@ -0,0 +1,228 @@
+use psl::schema_ast::ast::{Model, Top, WithDocumentation, WithName};
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use crate::ImportOptions;
+use crate::annotation::{FieldAnnotation, Visibility};
+use crate::code::{
+    StructField, derives, get_id_fields, get_model_struct_name, get_named_field_list, handle_derive,
+};
+use crate::transform::get_field_name;
+use crate::unique::{HASH_DERIVES, is_hashable, merge_key_derives};
+
+/// The wrapped types an ID newtype implements `Display` and `FromStr` for
+const STRING_CONVERTIBLE: [&str; 15] = [
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "String",
+    "uuid::Uuid",
+    "bson::oid::ObjectId",
+];
+
+/// The name of the ID newtype of a model, and the name of its `@id` field.
+///
+/// Only models with a single `@id` field marked with `@prs.newtype` (or every model, with `id_newtypes`) have one.
+/// An `@id` field with `@prs.type` or a `patch` keeps its type, so its model has none.
+pub fn get_id_newtype<'a>(
+    schema: &'a Vec<Top>,
+    model_name: &str,
+    import_options: &ImportOptions,
+) -> Option<(Ident, &'a str)> {
+    let model = schema.iter().find_map(|top| match top {
+        Top::Model(model) if model.name() == model_name => Some(model),
+        _ => None,
+    })?;
+    let struct_name = get_model_struct_name(schema, model_name, import_options)?;
+
+    if model.attributes.iter().any(|a| a.name() == "id") {
+        return None;
+    }
+    let [id_name] = get_id_fields(model)[..] else {
+        return None;
+    };
+    let (_, id_field) = model
+        .iter_fields()
+        .find(|(_, field)| field.name() == id_name)?;
+
+    let FieldAnnotation {
+        rename,
+        skip,
+        type_,
+        newtype,
+        ..
+    } = match id_field.documentation() {
+        Some(d) => d.into(),
+        None => FieldAnnotation::default(),
+    };
+    if skip || type_.is_some() || !(newtype || import_options.id_newtypes.unwrap_or(false)) {
+        return None;
+    }
+
+    let field_name = get_field_name(rename.unwrap_or(id_name.to_string()));
+    let patched = import_options
+        .patch
+        .iter()
+        .flatten()
+        .filter(|p| p.ident == struct_name)
+        .flat_map(|p| &p.fields)
+        .any(|f| f.ident.as_ref() == Some(&field_name));
+    if patched {
+        return None;
+    }
+
+    Some((format_ident!("{}Id", struct_name), id_name))
+}
+
+/// Replaces the type of the `@id` field of a model, and of its foreign keys (`@relation(fields: [..], references: [..])`)
+/// which reference an `@id` field, with ID newtypes.
+///
+/// Returns the ID newtype of the model, and the type it wraps.
+pub fn apply_id_newtypes(
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    model: &Model,
+    fields: &mut [StructField],
+) -> Option<(Ident, proc_macro2::TokenStream)> {
+    let mut id_newtype = None;
+    if let Some((newtype, id_name)) = get_id_newtype(schema, model.name(), import_options) {
+        if let Some(field) = fields
+            .iter_mut()
+            .find(|field| field.field.name() == id_name)
+        {
+            let inner = std::mem::replace(&mut field.type_name, quote! { #newtype });
+            field.serde_as = None;
+            field.newtype = Some(newtype.clone());
+            id_newtype = Some((newtype, inner));
+        }
+    }
+
+    for (_, relation_field) in model.iter_fields() {
+        let Some(relation) = relation_field
+            .attributes
+            .iter()
+            .find(|a| a.name() == "relation")
+        else {
+            continue;
+        };
+        let [reference] = get_named_field_list(relation, "references")[..] else {
+            continue;
+        };
+        let [foreign_key] = get_named_field_list(relation, "fields")[..] else {
+            continue;
+        };
+        let Some((newtype, id_name)) =
+            get_id_newtype(schema, relation_field.field_type.name(), import_options)
+        else {
+            continue;
+        };
+        if id_name != reference {
+            continue;
+        }
+
+        let Some(field) = fields.iter_mut().find(|field| {
+            !field.is_relation
+                && !field.type_override
+                && field.patch.is_none()
+                && field.field.name() == foreign_key
+        }) else {
+            continue;
+        };
+
+        let type_name = if field.field.arity.is_list() {
+            quote! { Vec<#newtype> }
+        } else {
+            quote! { #newtype }
+        };
+        field.type_name = if field.field.arity.is_optional() {
+            quote! { Option<#type_name> }
+        } else {
+            type_name
+        };
+        field.serde_as = None;
+        field.newtype = Some(newtype);
+    }
+
+    id_newtype
+}
+
+/// The ID newtype of a model, with `From` conversions to and from the type it wraps,
+/// and `Display` and `FromStr` when the wrapped type is known to implement them.
+///
+/// Derives the model's `derive` list with `Debug`, `Clone` and `PartialEq` added, and `Eq` and `Hash` unless it wraps a float,
+/// and is `#[serde(transparent)]`.
+pub fn handle_id_newtype(
+    newtype: &Ident,
+    inner: &proc_macro2::TokenStream,
+    visibility: Visibility,
+    derive: Option<Vec<String>>,
+    default: bool,
+) -> proc_macro2::TokenStream {
+    let serde = if derives(&derive, "Serialize") || derives(&derive, "Deserialize") {
+        Some(quote! { #[serde(transparent)] })
+    } else {
+        None
+    };
+    let mut derive = merge_key_derives(derive, |trait_name| {
+        !HASH_DERIVES.contains(&trait_name) || is_hashable(inner)
+    });
+    if default {
+        derive.push("Default".to_string());
+    }
+    let derive = handle_derive(Some(derive));
+
+    let string_conversions = if is_string_convertible(inner) {
+        Some(quote! {
+            impl std::fmt::Display for #newtype {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    std::fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl std::str::FromStr for #newtype {
+                type Err = <#inner as std::str::FromStr>::Err;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.parse().map(#newtype)
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    quote! {
+        #derive
+        #serde
+        #visibility struct #newtype(#visibility #inner);
+
+        #string_conversions
+
+        impl From<#inner> for #newtype {
+            fn from(value: #inner) -> Self {
+                #newtype(value)
+            }
+        }
+
+        impl From<#newtype> for #inner {
+            fn from(value: #newtype) -> Self {
+                value.0
+            }
+        }
+    }
+}
+
+/// Whether a type is known to implement `Display` and `FromStr`: integers, `String`, `Uuid` and `ObjectId`
+fn is_string_convertible(type_name: &proc_macro2::TokenStream) -> bool {
+    let type_name = type_name.to_string().replace(' ', "");
+    STRING_CONVERTIBLE.contains(&type_name.as_str())
+}
@@ -0,0 +1,828 @@
+use psl::schema_ast::ast::{Field, Top, WithName};
+use quote::format_ident;
+
+use crate::ImportOptions;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Case {
+    Snake,
+    Camel,
+    Pascal,
+    Kebab,
+    ScreamingSnake,
+    Unknown,
+}
+
+pub(crate) fn identify_case(input: &str) -> Case {
+    if input.is_empty() {
+        return Case::Snake;
+    }
+
+    let chars = input.chars().collect::<Vec<_>>();
+
+    if chars.iter().all(|c| c.is_uppercase() || c.is_numeric()) {
+        return Case::ScreamingSnake;
+    }
+
+    if chars.contains(&'_') {
+        if chars
+            .iter()
+            .all(|c| c.is_lowercase() || c.is_numeric() || *c == '_')
+        {
+            return Case::Snake;
+        }
+
+        if chars
+            .iter()
+            .all(|c| c.is_uppercase() || c.is_numeric() || *c == '_')
+        {
+            return Case::ScreamingSnake;
+        }
+
+        return Case::Unknown;
+    }
+
+    if chars.contains(&'-') {
+        if chars
+            .iter()
+            .all(|c| c.is_lowercase() || c.is_numeric() || *c == '-')
+        {
+            return Case::Kebab;
+        }
+
+        return Case::Unknown;
+    }
+
+    if chars.iter().any(|c| c.is_uppercase()) && !chars.contains(&'_') {
+        if chars[0].is_uppercase() {
+            return Case::Pascal;
+        }
+        return Case::Camel;
+    }
+
+    if chars.iter().all(|c| c.is_lowercase() || c.is_numeric()) {
+        return Case::Snake;
+    }
+
+    Case::Unknown
+}
+
+// Split by uppercase (keep), underscore (discard), hyphen (discard)
+// Lowercase all characters
+// Join with underscore
+pub(crate) fn to_snake_case(input: &str) -> String {
+    let case = identify_case(input);
+
+    match case {
+        Case::Snake => input.to_string(),
+        Case::Camel => {
+            let mut result = String::new();
+            let mut capitalize_next = false;
+
+            for c in input.chars() {
+                if c.is_uppercase() {
+                    if !result.is_empty() && !capitalize_next {
+                        result.push('_');
+                    }
+                    result.push(c.to_ascii_lowercase());
+                    capitalize_next = false;
+                } else {
+                    result.push(c);
+                }
+            }
+
+            result
+        }
+        Case::Pascal => {
+            let mut result = String::new();
+            let mut capitalize_next = true;
+
+            for c in input.chars() {
+                if c.is_uppercase() {
+                    if !result.is_empty() && !capitalize_next {
+                        result.push('_');
+                    }
+                    result.push(c.to_ascii_lowercase());
+                    capitalize_next = false;
+                } else {
+                    result.push(c);
+                }
+            }
+
+            result
+        }
+        Case::Kebab => input.replace('-', "_").to_ascii_lowercase(),
+        Case::ScreamingSnake => input.to_ascii_lowercase(),
+        Case::Unknown => {
+            let mut result = String::new();
+            let mut capitalize_next = false;
+
+            for c in input.chars() {
+                if c.is_uppercase() {
+                    if !result.is_empty() && !capitalize_next {
+                        result.push('_');
+                    }
+                    result.push(c.to_ascii_lowercase());
+                    capitalize_next = false;
+                } else {
+                    result.push(c);
+                }
+            }
+
+            result
+        }
+    }
+}
+
+pub(crate) fn to_pascal_case(input: &str) -> String {
+    let case = identify_case(input);
+
+    match case {
+        Case::Snake => {
+            let mut result = String::new();
+            let mut capitalize_next = true;
+
+            for c in input.chars() {
+                if c == '_' {
+                    capitalize_next = true;
+                } else if capitalize_next {
+                    result.push(c.to_ascii_uppercase());
+                    capitalize_next = false;
+                } else {
+                    result.push(c);
+                }
+            }
+
+            result
+        }
+        Case::Camel => {
+            let mut result = input.get(0..1).unwrap_or_default().to_ascii_uppercase();
+            let mut capitalize_next = true;
+
+            for c in input.chars().skip(1) {
+                if c.is_uppercase() {
+                    if !result.is_empty() && !capitalize_next {
+                        result.push('_');
+                    }
+                    result.push(c.to_ascii_uppercase());
+                    capitalize_next = false;
+                } else {
+                    result.push(c);
+                }
+            }
+
+            result
+        }
+        Case::Pascal => input.to_string(),
+        Case::Kebab => {
+            let mut result = String::new();
+            let mut capitalize_next = true;
+
+            for c in input.chars() {
+                if c == '-' {
+                    capitalize_next = true;
+                } else if capitalize_next {
+                    result.push(c.to_ascii_uppercase());
+                    capitalize_next = false;
+                } else {
+                    result.push(c);
+                }
+            }
+
+            result
+        }
+        Case::ScreamingSnake => {
+            // `HELLO_WORLD` -> `HelloWorld`
+            let mut result = String::new();
+            let mut capitalize_next = true;
+            for c in input.chars() {
+                if c == '_' {
+                    capitalize_next = true;
+                } else if capitalize_next {
+                    result.push(c.to_ascii_uppercase());
+                    capitalize_next = false;
+                } else {
+                    result.push(c.to_ascii_lowercase());
+                }
+            }
+            result
+        }
+        Case::Unknown => input.to_string(),
+    }
+}
+
+/// The connector of the schema's `datasource` block.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Provider {
+    CockroachDb,
+    MongoDb,
+    MySql,
+    PostgreSql,
+    Sqlite,
+    SqlServer,
+    /// No `datasource` block, or an unrecognized provider.
+    /// Type mapping falls back to the cargo features alone.
+    Unknown,
+}
+
+impl From<&str> for Provider {
+    fn from(s: &str) -> Self {
+        match s {
+            "cockroachdb" => Provider::CockroachDb,
+            "mongodb" => Provider::MongoDb,
+            "mysql" => Provider::MySql,
+            "postgresql" | "postgres" => Provider::PostgreSql,
+            "sqlite" => Provider::Sqlite,
+            "sqlserver" => Provider::SqlServer,
+            _ => Provider::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Datasource {
+    /// The name of the `datasource` block, used as the prefix of native type attributes (e.g. `db` in `@db.ObjectId`)
+    pub name: String,
+    pub provider: Provider,
+}
+
+impl Default for Datasource {
+    fn default() -> Self {
+        Datasource {
+            name: "db".to_string(),
+            provider: Provider::Unknown,
+        }
+    }
+}
+
+impl Datasource {
+    /// Finds the first `datasource` block of the schema.
+    pub(crate) fn from_tops(tops: &[Top]) -> Self {
+        tops.iter()
+            .find_map(|top| match top {
+                Top::Source(source) => {
+                    let provider = source
+                        .properties
+                        .iter()
+                        .find(|property| property.name.name == "provider")
+                        .and_then(|property| property.value.as_ref())
+                        .and_then(|value| value.as_string_value())
+                        .map(|(provider, _)| Provider::from(provider))
+                        .unwrap_or(Provider::Unknown);
+
+                    Some(Datasource {
+                        name: source.name.name.clone(),
+                        provider,
+                    })
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// The native type of a field, without the datasource prefix
+    pub(crate) fn native_type<'a>(&self, field: &'a Field) -> Option<&'a str> {
+        field.attributes.iter().find_map(|attr| {
+            attr.name()
+                .strip_prefix(self.name.as_str())
+                .and_then(|name| name.strip_prefix('.'))
+        })
+    }
+
+    /// Whether bson types should be used. Without a known provider, the `bson` feature alone decides.
+    fn uses_bson(&self) -> bool {
+        cfg!(feature = "bson") && matches!(self.provider, Provider::MongoDb | Provider::Unknown)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DateTimeBackend {
+    Bson,
+    Chrono,
+    Jiff,
+    String,
+    Time,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ObjectIdBackend {
+    Bson,
+    String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DecimalBackend {
+    BigDecimal,
+    RustDecimal,
+    String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BytesBackend {
+    Bson,
+    Bytes,
+    Vec,
+}
+
+/// The Rust types used for one `import_types!` invocation.
+///
+/// Resolved from the `import_types!` options, then the cargo features, then the connector defaults.
+#[derive(Debug, Clone)]
+pub(crate) struct TypeMapping {
+    pub datasource: Datasource,
+    pub datetime: DateTimeBackend,
+    pub object_id: ObjectIdBackend,
+    /// The Rust type of `Json` fields
+    pub json: String,
+    pub decimal: DecimalBackend,
+    pub bytes: BytesBackend,
+}
+
+impl TypeMapping {
+    pub(crate) fn new(import_options: &ImportOptions, datasource: Datasource) -> syn::Result<Self> {
+        let datetime = match import_options.datetime.as_deref() {
+            Some("bson") => DateTimeBackend::Bson,
+            Some("chrono") => DateTimeBackend::Chrono,
+            Some("jiff") => DateTimeBackend::Jiff,
+            Some("string") => DateTimeBackend::String,
+            Some("time") => DateTimeBackend::Time,
+            Some(other) => {
+                return Err(unknown_option(
+                    "datetime",
+                    other,
+                    "chrono, time, jiff, bson, string",
+                ));
+            }
+            None => {
+                if cfg!(feature = "chrono") {
+                    DateTimeBackend::Chrono
+                } else if datasource.uses_bson() {
+                    DateTimeBackend::Bson
+                } else {
+                    DateTimeBackend::String
+                }
+            }
+        };
+
+        let object_id = match import_options.object_id.as_deref() {
+            Some("bson") => ObjectIdBackend::Bson,
+            Some("string") => ObjectIdBackend::String,
+            Some(other) => return Err(unknown_option("object_id", other, "bson, string")),
+            None => {
+                if datasource.uses_bson() {
+                    ObjectIdBackend::Bson
+                } else {
+                    ObjectIdBackend::String
+                }
+            }
+        };
+
+        let json = match import_options.json.as_deref() {
+            Some("serde_json") | None => "serde_json::Value".to_string(),
+            Some(path) => {
+                if syn::parse_str::<syn::Type>(path).is_err() {
+                    return Err(unknown_option(
+                        "json",
+                        path,
+                        "serde_json, or the path to a custom type",
+                    ));
+                }
+                path.to_string()
+            }
+        };
+
+        let decimal = match import_options.decimal.as_deref() {
+            Some("bigdecimal") => DecimalBackend::BigDecimal,
+            Some("rust_decimal") => DecimalBackend::RustDecimal,
+            Some("string") => DecimalBackend::String,
+            Some(other) => {
+                return Err(unknown_option(
+                    "decimal",
+                    other,
+                    "rust_decimal, bigdecimal, string",
+                ));
+            }
+            None => {
+                // Both backends (de)serialize as a string by default, matching Prisma Client
+                if cfg!(feature = "rust_decimal") {
+                    DecimalBackend::RustDecimal
+                } else if cfg!(feature = "bigdecimal") {
+                    DecimalBackend::BigDecimal
+                } else {
+                    DecimalBackend::String
+                }
+            }
+        };
+
+        let bytes = match import_options.bytes.as_deref() {
+            Some("bson") => BytesBackend::Bson,
+            Some("bytes") => BytesBackend::Bytes,
+            Some("vec") => BytesBackend::Vec,
+            Some(other) => return Err(unknown_option("bytes", other, "vec, bytes, bson")),
+            None => {
+                let is_mongodb = match datasource.provider {
+                    Provider::MongoDb => true,
+                    Provider::Unknown => cfg!(feature = "mongodb"),
+                    _ => false,
+                };
+                if is_mongodb && datasource.uses_bson() {
+                    BytesBackend::Bson
+                } else if cfg!(feature = "bytes") {
+                    BytesBackend::Bytes
+                } else {
+                    BytesBackend::Vec
+                }
+            }
+        };
+
+        Ok(TypeMapping {
+            datasource,
+            datetime,
+            object_id,
+            json,
+            decimal,
+            bytes,
+        })
+    }
+}
+
+impl TypeMapping {
+    /// The type of every `DateTime` field, whatever its native type.
+    ///
+    /// Prisma Client returns every `DateTime` as an ISO 8601 instant, e.g. `2025-01-31T00:00:00.000Z` for a `@db.Date`
+    /// and `1970-01-01T12:30:00.000Z` for a `@db.Time`, which date-only, time-only and naive types can not deserialize.
+    pub(crate) fn datetime_type(&self) -> String {
+        let t = match self.datetime {
+            DateTimeBackend::Bson => "bson::DateTime",
+            DateTimeBackend::Chrono => "chrono::DateTime<chrono::Utc>",
+            DateTimeBackend::Jiff => "jiff::Timestamp",
+            DateTimeBackend::String => "String",
+            DateTimeBackend::Time => "time::OffsetDateTime",
+        };
+        t.to_string()
+    }
+
+    pub(crate) fn object_id_type(&self) -> String {
+        match self.object_id {
+            ObjectIdBackend::Bson => "bson::oid::ObjectId".to_string(),
+            ObjectIdBackend::String => "String".to_string(),
+        }
+    }
+
+    pub(crate) fn decimal_type(&self) -> String {
+        match self.decimal {
+            DecimalBackend::BigDecimal => "bigdecimal::BigDecimal".to_string(),
+            DecimalBackend::RustDecimal => "rust_decimal::Decimal".to_string(),
+            DecimalBackend::String => "String".to_string(),
+        }
+    }
+
+    pub(crate) fn bytes_type(&self) -> String {
+        match self.bytes {
+            BytesBackend::Bson => "bson::Binary".to_string(),
+            BytesBackend::Bytes => "bytes::Bytes".to_string(),
+            BytesBackend::Vec => "Vec<u8>".to_string(),
+        }
+    }
+}
+
+fn unknown_option(option: &str, value: &str, available: &str) -> syn::Error {
+    syn::Error::new(
+        proc_macro2::Span::call_site(),
+        format!("Unknown `{option}` option: {value}\nAvailable options: {available}"),
+    )
+}
+
+pub(crate) fn convert_field_to_type(
+    field: &Field,
+    import_options: &ImportOptions,
+    type_mapping: &TypeMapping,
+) -> String {
+    let scalar = convert_field_to_scalar(field, import_options, type_mapping);
+    wrap_arity(field, scalar)
+}
+
+/// The `serde_with::serde_as` adapter for a field, if the field needs one.
+pub(crate) fn convert_field_to_serde_as(
+    field: &Field,
+    import_options: &ImportOptions,
+    type_mapping: &TypeMapping,
+) -> Option<String> {
+    let scalar = convert_field_to_scalar(field, import_options, type_mapping);
+
+    let adapter = match scalar.as_str() {
+        "Vec<u8>" | "bytes::Bytes" if cfg!(feature = "bytes_base64") => {
+            "serde_with::base64::Base64"
+        }
+        // `bytes::Bytes` already serializes as raw bytes
+        "Vec<u8>" if cfg!(feature = "bytes_raw") => "serde_with::Bytes",
+        _ => return None,
+    };
+
+    Some(wrap_arity(field, adapter.to_string()))
+}
+
+fn wrap_arity(field: &Field, scalar: String) -> String {
+    let maybe_list = if field.arity.is_list() {
+        format!("Vec<{}>", scalar)
+    } else {
+        scalar
+    };
+
+    let maybe_option = if field.arity.is_optional() {
+        format!("Option<{}>", maybe_list)
+    } else {
+        maybe_list
+    };
+
+    maybe_option
+}
+
+pub(crate) fn convert_field_to_scalar(
+    field: &Field,
+    import_options: &ImportOptions,
+    type_mapping: &TypeMapping,
+) -> String {
+    let datasource = &type_mapping.datasource;
+    let field_type_name = field.field_type.name();
+
+    // Native type attributes (e.g. `@db.Uuid`) take precedence over the Prisma scalar type
+    if let Some(scalar) = datasource.native_type(field).and_then(|native_type| {
+        convert_native_type(
+            datasource.provider,
+            native_type,
+            field_type_name,
+            type_mapping,
+        )
+    }) {
+        return scalar;
+    }
+
+    let scalar = match field_type_name {
+        "Boolean" => "bool".to_string(),
+        "Int" => match datasource.provider {
+            Provider::MongoDb => "i64".to_string(),
+            // The `mongodb` feature only applies to schemas without a known connector
+            Provider::Unknown if cfg!(feature = "mongodb") => "i64".to_string(),
+            _ => "i32".to_string(),
+        },
+        "BigInt" => "i64".to_string(),
+        "Float" => "f64".to_string(),
+        "String" => "String".to_string(),
+        "Json" => type_mapping.json.clone(),
+        "DateTime" => type_mapping.datetime_type(),
+        "Decimal" => type_mapping.decimal_type(),
+        "Bytes" => type_mapping.bytes_type(),
+        _ => {
+            let field_type_name = if let Some(prefix) = &import_options.prefix {
+                format!("{}{}", prefix, field_type_name)
+            } else {
+                field_type_name.to_string()
+            };
+            to_pascal_case(&field_type_name)
+        }
+    };
+
+    scalar
+}
+
+/// The Rust type of a native type attribute, keyed on the connector and the native type name.
+///
+/// Native types not listed here fall back to the Prisma scalar type of the field (e.g. `@db.VarChar(n)` -> `String`).
+/// Every date and time native type (e.g. `@db.Date`) falls back to the `DateTime` type, see [`TypeMapping::datetime_type`].
+/// Without a known provider, only the native types of previous versions are recognized.
+fn convert_native_type(
+    provider: Provider,
+    native_type: &str,
+    field_type_name: &str,
+    type_mapping: &TypeMapping,
+) -> Option<String> {
+    use Provider::*;
+
+    let scalar = match (provider, native_type) {
+        // Integers
+        (MySql, "TinyInt") if field_type_name == "Boolean" => "bool".to_string(),
+        (MySql, "TinyInt") => "i8".to_string(),
+        (MySql, "UnsignedTinyInt") | (SqlServer, "TinyInt") => "u8".to_string(),
+        (PostgreSql | MySql | SqlServer, "SmallInt") | (CockroachDb, "Int2") => "i16".to_string(),
+        (MySql, "UnsignedSmallInt") => "u16".to_string(),
+        (MongoDb | MySql | SqlServer | Unknown, "Int")
+        | (MySql, "MediumInt")
+        | (PostgreSql, "Integer")
+        | (CockroachDb, "Int4") => "i32".to_string(),
+        (MySql, "UnsignedInt" | "UnsignedMediumInt") | (PostgreSql | CockroachDb, "Oid") => {
+            "u32".to_string()
+        }
+        (MongoDb | Unknown, "Long")
+        | (PostgreSql | MySql | SqlServer, "BigInt")
+        | (CockroachDb, "Int8") => "i64".to_string(),
+        (MySql, "UnsignedBigInt") => "u64".to_string(),
+        (MySql, "Year") => "i32".to_string(),
+        (MySql, "Bit") if field_type_name == "Boolean" => "bool".to_string(),
+        (SqlServer, "Bit") => "bool".to_string(),
+
+        // Floats
+        (PostgreSql | SqlServer, "Real") | (MySql, "Float") | (CockroachDb, "Float4") => {
+            "f32".to_string()
+        }
+        (PostgreSql, "DoublePrecision")
+        | (MySql | MongoDb, "Double")
+        | (SqlServer, "Float")
+        | (CockroachDb, "Float8") => "f64".to_string(),
+
+        // Decimals
+        (PostgreSql | CockroachDb | MySql | SqlServer | Unknown, "Decimal")
+        | (PostgreSql | SqlServer | Unknown, "Money")
+        | (SqlServer, "SmallMoney") => type_mapping.decimal_type(),
+
+        // Strings
+        (PostgreSql | CockroachDb, "Uuid") | (SqlServer, "UniqueIdentifier") => {
+            if cfg!(feature = "uuid") {
+                "uuid::Uuid".to_string()
+            } else {
+                "String".to_string()
+            }
+        }
+        (PostgreSql | CockroachDb, "Inet") => {
+            if cfg!(feature = "ipnetwork") {
+                "ipnetwork::IpNetwork".to_string()
+            } else {
+                "String".to_string()
+            }
+        }
+        (PostgreSql | CockroachDb, "Text" | "Char" | "VarChar" | "Bit" | "VarBit")
+        | (PostgreSql, "Citext" | "Xml")
+        | (CockroachDb, "String" | "CatalogSingleChar")
+        | (MySql, "Text" | "Char" | "VarChar" | "TinyText" | "MediumText" | "LongText")
+        | (SqlServer, "Char" | "NChar" | "VarChar" | "NVarChar" | "Text" | "NText" | "Xml")
+        | (MongoDb, "String") => "String".to_string(),
+        (MongoDb | Unknown, "ObjectId") => type_mapping.object_id_type(),
+
+        // JSON
+        (PostgreSql | CockroachDb, "Json" | "JsonB") | (MySql, "Json") => type_mapping.json.clone(),
+
+        // Bytes
+        (PostgreSql, "ByteA")
+        | (CockroachDb, "Bytes")
+        | (MongoDb, "BinData")
+        | (MySql, "Binary" | "VarBinary" | "TinyBlob" | "Blob" | "MediumBlob" | "LongBlob")
+        | (MySql, "Bit")
+        | (SqlServer, "Binary" | "VarBinary" | "Image") => type_mapping.bytes_type(),
+        (Unknown, "ByteA" | "Bytes" | "BinData" | "Binary" | "VarBinary" | "Blob" | "Image") => {
+            type_mapping.bytes_type()
+        }
+
+        _ => return None,
+    };
+
+    Some(scalar)
+}
+
+pub(crate) fn get_struct_name(name: String, import_options: &ImportOptions) -> proc_macro2::Ident {
+    let name = to_pascal_case(&name);
+    let name = if let Some(prefix) = &import_options.prefix {
+        format!("{}{}", prefix, name)
+    } else {
+        name.to_string()
+    };
+
+    // Handle special cases:
+    let name = match name.as_str() {
+        "type" => "_type".to_string(),
+        _ => name,
+    };
+
+    format_ident!("{}", name)
+}
+
+pub(crate) fn get_field_name(name: String) -> proc_macro2::Ident {
+    let name = to_snake_case(&name);
+    // Handle special cases:
+    let name = match name.as_str() {
+        "type" => "_type".to_string(),
+        _ => name,
+    };
+    format_ident!("{}", name)
+}
+
+pub(crate) fn get_enum_name(name: String, import_options: &ImportOptions) -> proc_macro2::Ident {
+    let name = to_pascal_case(&name);
+    let name = if let Some(prefix) = &import_options.prefix {
+        format!("{}{}", prefix, name)
+    } else {
+        name.to_string()
+    };
+
+    // Handle special cases:
+    let name = match name.as_str() {
+        "type" => "_type".to_string(),
+        _ => name,
+    };
+    format_ident!("{}", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identify_case() {
+        assert_eq!(identify_case("hello_world"), Case::Snake);
+        assert_eq!(identify_case("HelloWorld"), Case::Pascal);
+        assert_eq!(identify_case("helloWorld"), Case::Camel);
+        assert_eq!(identify_case("hello-world"), Case::Kebab);
+        assert_eq!(identify_case("HELLO_WORLD"), Case::ScreamingSnake);
+        assert_eq!(identify_case("helloworld"), Case::Snake);
+        assert_eq!(identify_case("hello_world_123"), Case::Snake);
+        assert_eq!(identify_case("Hello_World"), Case::Unknown);
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("HelloWorld"), "hello_world");
+        assert_eq!(to_snake_case("helloWorld"), "hello_world");
+        assert_eq!(to_snake_case("hello_world"), "hello_world");
+        assert_eq!(to_snake_case("HELLO_WORLD"), "hello_world");
+        assert_eq!(to_snake_case("helloworld"), "helloworld");
+    }
+
+    #[test]
+    fn test_provider_from_str() {
+        assert_eq!(Provider::from("mongodb"), Provider::MongoDb);
+        assert_eq!(Provider::from("postgresql"), Provider::PostgreSql);
+        assert_eq!(Provider::from("postgres"), Provider::PostgreSql);
+        assert_eq!(Provider::from("cockroachdb"), Provider::CockroachDb);
+        assert_eq!(Provider::from("mysql"), Provider::MySql);
+        assert_eq!(Provider::from("sqlite"), Provider::Sqlite);
+        assert_eq!(Provider::from("sqlserver"), Provider::SqlServer);
+        assert_eq!(Provider::from("unknown"), Provider::Unknown);
+    }
+
+    #[test]
+    fn test_type_mapping_options() {
+        let import_options = ImportOptions {
+            datetime: Some("jiff".to_string()),
+            object_id: Some("string".to_string()),
+            json: Some("my_crate::Json".to_string()),
+            ..Default::default()
+        };
+        let datasource = Datasource {
+            name: "db".to_string(),
+            provider: Provider::MongoDb,
+        };
+
+        let type_mapping = TypeMapping::new(&import_options, datasource).unwrap();
+        assert_eq!(type_mapping.datetime, DateTimeBackend::Jiff);
+        assert_eq!(type_mapping.object_id, ObjectIdBackend::String);
+        assert_eq!(type_mapping.json, "my_crate::Json");
+    }
+
+    #[test]
+    fn test_type_mapping_unknown_option() {
+        let import_options = ImportOptions {
+            datetime: Some("moment".to_string()),
+            ..Default::default()
+        };
+
+        assert!(TypeMapping::new(&import_options, Datasource::default()).is_err());
+    }
+
+    // #[test]
+    // fn test_to_camel_case() {
+    //     assert_eq!(to_camel_case("hello_world"), "helloWorld");
+    //     assert_eq!(to_camel_case("helloWorld"), "helloWorld");
+    //     assert_eq!(to_camel_case("hello-world"), "helloWorld");
+    //     assert_eq!(to_camel_case("HELLO_WORLD"), "helloWorld");
+    // }
+
+    #[test]
+    fn test_to_pascal_case() {
+        assert_eq!(to_pascal_case("hello_world"), "HelloWorld");
+        assert_eq!(to_pascal_case("helloWorld"), "HelloWorld");
+        assert_eq!(to_pascal_case("hello-world"), "HelloWorld");
+        assert_eq!(to_pascal_case("HELLO_WORLD"), "HelloWorld");
+        assert_eq!(to_pascal_case("helloworld"), "Helloworld");
+        assert_eq!(to_pascal_case("HelloWorld"), "HelloWorld");
+    }
+
+    // #[test]
+    // fn test_to_kebab_case() {
+    //     assert_eq!(to_kebab_case("HelloWorld"), "hello-world");
+    //     assert_eq!(to_kebab_case("helloWorld"), "hello-world");
+    //     assert_eq!(to_kebab_case("hello-world"), "hello-world");
+    //     assert_eq!(to_kebab_case("Hello-World"), "hello-world");
+    //     assert_eq!(to_kebab_case("HELLO_WORLD"), "hello-world");
+    //     assert_eq!(to_kebab_case("helloworld"), "helloworld");
+    // }
+
+    // #[test]
+    // fn test_to_screaming_snake_case() {
+    //     assert_eq!(to_screaming_snake_case("HelloWorld"), "HELLO_WORLD");
+    //     assert_eq!(to_screaming_snake_case("helloWorld"), "HELLO_WORLD");
+    //     assert_eq!(to_screaming_snake_case("hello_world"), "HELLO_WORLD");
+    //     assert_eq!(to_screaming_snake_case("Hello_World"), "HELLO_WORLD");
+    //     assert_eq!(to_screaming_snake_case("HELLO_WORLD"), "HELLO_WORLD");
+    //     assert_eq!(to_screaming_snake_case("helloworld"), "HELLOWORLD");
+    //     assert_eq!(to_screaming_snake_case("hello-world"), "HELLO_WORLD");
+    // }
+}
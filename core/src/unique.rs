@@ -0,0 +1,367 @@
+use psl::schema_ast::ast::{Attribute, Model, Top, WithDocumentation, WithName};
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use crate::ImportOptions;
+use crate::annotation::{EnumAnnotation, TypeAnnotation, Visibility};
+use crate::code::{StructField, derives, get_field_list, get_id_fields, handle_derive};
+use crate::transform::to_pascal_case;
+
+/// The traits a key type derives when every field supports them
+const KEY_DERIVES: [&str; 3] = ["Debug", "Clone", "PartialEq"];
+/// The traits a key type derives when every field supports them, so it can be used as a `HashMap` key
+pub const HASH_DERIVES: [&str; 2] = ["Eq", "Hash"];
+
+/// A primary key or unique constraint of a model
+struct Constraint<'a> {
+    /// The Prisma name of the constraint, e.g. `email` or `firstName_lastName`
+    name: String,
+    fields: Vec<&'a StructField<'a>>,
+}
+
+/// `<Model>PrimaryKey` and `<Model>WhereUniqueInput` from `@id`, `@@id`, `@unique` and `@@unique`.
+///
+/// The primary key is a newtype for a single `@id`, and a struct for a compound `@@id`.
+/// `<Model>WhereUniqueInput` has a variant per constraint, which serializes like a Prisma Client `WhereUniqueInput`.
+/// Constraints with a skipped field are left out, and so are constraints with the same fields as a previous one.
+///
+/// `Debug`, `Clone`, `PartialEq`, `Eq` and `Hash` are added to the model's `derive` list when every key field is known to implement them.
+/// `primary_key()` is only generated when the key fields can be cloned.
+pub fn handle_unique_keys(
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    model: &Model,
+    struct_name: &Ident,
+    visibility: Visibility,
+    derive: Option<Vec<String>>,
+    fields: &[StructField],
+) -> proc_macro2::TokenStream {
+    let serde = derives(&derive, "Serialize") || derives(&derive, "Deserialize");
+    let implements = |fields: &[&StructField], trait_name: &str| {
+        fields
+            .iter()
+            .all(|field| field_implements(schema, import_options, field, trait_name))
+    };
+
+    let primary_key = get_primary_key(model, fields);
+    let unique_constraints = get_unique_constraints(model, fields, primary_key.as_ref());
+    let mut output = quote! {};
+    let mut variants = vec![];
+
+    if let Some(primary_key) = &primary_key {
+        let key_name = format_ident!("{}PrimaryKey", struct_name);
+        let key_derive = handle_derive(Some(merge_key_derives(derive.clone(), |trait_name| {
+            implements(&primary_key.fields, trait_name)
+        })));
+        let field_names = primary_key
+            .fields
+            .iter()
+            .map(|field| &field.name)
+            .collect::<Vec<_>>();
+        // A model deriving `Clone` can only have fields which implement it
+        let cloneable = derives(&derive, "Clone") || implements(&primary_key.fields, "Clone");
+
+        let key = if let [field] = primary_key.fields.as_slice() {
+            let field_name = &field.name;
+            let type_name = field.rust_type();
+            let method = cloneable.then(|| {
+                quote! {
+                    impl #struct_name {
+                        /// The primary key (`@id`) of this record
+                        #visibility fn primary_key(&self) -> #key_name {
+                            #key_name(self.#field_name.clone())
+                        }
+                    }
+                }
+            });
+            quote! {
+                #key_derive
+                #visibility struct #key_name(#visibility #type_name);
+
+                #method
+            }
+        } else {
+            let key_fields = primary_key
+                .fields
+                .iter()
+                .map(|field| key_field(field, Some(field.visibility), serde));
+            let method = cloneable.then(|| {
+                quote! {
+                    impl #struct_name {
+                        /// The compound primary key (`@@id`) of this record
+                        #visibility fn primary_key(&self) -> #key_name {
+                            #key_name {
+                                #(#field_names: self.#field_names.clone(),)*
+                            }
+                        }
+                    }
+                }
+            });
+            quote! {
+                #key_derive
+                #visibility struct #key_name {
+                    #(#key_fields)*
+                }
+
+                #method
+            }
+        };
+        output.extend(key);
+
+        let variant = variant_name(primary_key);
+        let rename = serde_rename(&primary_key.name, serde);
+        variants.push(quote! {
+            #rename
+            #variant(#key_name),
+        });
+    }
+
+    for constraint in &unique_constraints {
+        let variant = variant_name(constraint);
+        let rename = serde_rename(&constraint.name, serde);
+        if let [field] = constraint.fields.as_slice() {
+            let type_name = field.rust_type();
+            variants.push(quote! {
+                #rename
+                #variant(#type_name),
+            });
+        } else {
+            let key_fields = constraint
+                .fields
+                .iter()
+                .map(|field| key_field(field, None, serde));
+            variants.push(quote! {
+                #rename
+                #variant {
+                    #(#key_fields)*
+                },
+            });
+        }
+    }
+
+    if variants.is_empty() {
+        return output;
+    }
+
+    let key_fields = primary_key
+        .iter()
+        .chain(&unique_constraints)
+        .flat_map(|constraint| constraint.fields.iter().copied())
+        .collect::<Vec<_>>();
+    let input_derive = handle_derive(Some(merge_key_derives(derive, |trait_name| {
+        implements(&key_fields, trait_name)
+    })));
+    let input_name = format_ident!("{}WhereUniqueInput", struct_name);
+    output.extend(quote! {
+        #input_derive
+        #visibility enum #input_name {
+            #(#variants)*
+        }
+    });
+
+    if let Some(primary_key) = &primary_key {
+        let key_name = format_ident!("{}PrimaryKey", struct_name);
+        let variant = variant_name(primary_key);
+        output.extend(quote! {
+            impl From<#key_name> for #input_name {
+                fn from(value: #key_name) -> Self {
+                    #input_name::#variant(value)
+                }
+            }
+        });
+    }
+
+    output
+}
+
+/// The model derives without `Default`, and the traits of `KEY_DERIVES` and `HASH_DERIVES` which are missing and `implemented`
+pub fn merge_key_derives(
+    derive: Option<Vec<String>>,
+    implemented: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    let mut derive = derive
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|d| d.trim().rsplit("::").next() != Some("Default"))
+        .collect::<Vec<_>>();
+    for key_derive in KEY_DERIVES.into_iter().chain(HASH_DERIVES) {
+        if !derives(&Some(derive.clone()), key_derive) && implemented(key_derive) {
+            derive.push(key_derive.to_string());
+        }
+    }
+    derive
+}
+
+/// Whether a Rust type is known to implement `Eq` and `Hash`, which floats and JSON values do not
+pub fn is_hashable(type_name: &proc_macro2::TokenStream) -> bool {
+    let type_name = type_name.to_string().replace(' ', "");
+    !type_name
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+        .any(|segment| matches!(segment, "f32" | "f64" | "serde_json::Value"))
+}
+
+/// Whether the type of a key field is known to implement a trait of `KEY_DERIVES` or `HASH_DERIVES`.
+///
+/// Scalar types implement all of them, except `Eq` and `Hash` for `Float` and `Json`.
+/// Enums and composite types implement their `derive` list. Patched and `@prs.type` fields are unknown.
+fn field_implements(
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    field: &StructField,
+    trait_name: &str,
+) -> bool {
+    if field.type_override || field.patch.is_some() {
+        return false;
+    }
+
+    let type_name = field.field.field_type.name();
+    if let Some(derive) = declared_derive(schema, import_options, type_name) {
+        return derives(&derive, trait_name);
+    }
+
+    !HASH_DERIVES.contains(&trait_name)
+        || (is_hashable(&field.rust_type()) && !matches!(type_name, "Float" | "Json"))
+}
+
+/// The `derive` list of an enum or composite type of the schema
+fn declared_derive(
+    schema: &Vec<Top>,
+    import_options: &ImportOptions,
+    type_name: &str,
+) -> Option<Option<Vec<String>>> {
+    schema.iter().find_map(|top| match top {
+        Top::Enum(enum_type) if enum_type.name() == type_name => {
+            let EnumAnnotation { derive, .. } = match enum_type.documentation() {
+                Some(d) => d.into(),
+                None => EnumAnnotation::default(),
+            };
+            Some(derive.or(import_options.derive.clone()))
+        }
+        Top::CompositeType(composite_type) if composite_type.name() == type_name => {
+            let TypeAnnotation { derive, .. } = match composite_type.documentation() {
+                Some(d) => d.into(),
+                None => TypeAnnotation::default(),
+            };
+            Some(derive.or(import_options.derive.clone()))
+        }
+        _ => None,
+    })
+}
+
+fn get_primary_key<'a>(model: &Model, fields: &'a [StructField<'a>]) -> Option<Constraint<'a>> {
+    let name = match model.attributes.iter().find(|a| a.name() == "id") {
+        Some(attribute) => get_constraint_name(attribute),
+        None => None,
+    };
+    get_constraint(name, &get_id_fields(model), fields)
+}
+
+/// `@unique` and `@@unique` constraints, without those on the same fields as the primary key or a previous constraint,
+/// e.g. an `@id` field which is also `@unique`
+fn get_unique_constraints<'a>(
+    model: &Model,
+    fields: &'a [StructField<'a>],
+    primary_key: Option<&Constraint<'a>>,
+) -> Vec<Constraint<'a>> {
+    let field_constraints = model
+        .iter_fields()
+        .filter(|(_, field)| field.attributes.iter().any(|a| a.name() == "unique"))
+        .filter_map(|(_, field)| get_constraint(None, &[field.name()], fields));
+
+    let block_constraints = model
+        .attributes
+        .iter()
+        .filter(|a| a.name() == "unique")
+        .filter_map(|attribute| {
+            get_constraint(
+                get_constraint_name(attribute),
+                &get_field_list(attribute),
+                fields,
+            )
+        });
+
+    let mut constraints: Vec<Constraint> = vec![];
+    for constraint in field_constraints.chain(block_constraints) {
+        let duplicate = primary_key
+            .into_iter()
+            .chain(&constraints)
+            .any(|previous| variant_name(previous) == variant_name(&constraint));
+        if !duplicate {
+            constraints.push(constraint);
+        }
+    }
+    constraints
+}
+
+/// `None` if a field of the constraint is not generated
+fn get_constraint<'a>(
+    name: Option<&str>,
+    field_names: &[&str],
+    fields: &'a [StructField<'a>],
+) -> Option<Constraint<'a>> {
+    if field_names.is_empty() {
+        return None;
+    }
+
+    let constraint_fields = field_names
+        .iter()
+        .map(|field_name| {
+            fields
+                .iter()
+                .find(|field| !field.is_relation && field.field.name() == *field_name)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Constraint {
+        // Prisma names a compound constraint by joining its fields with `_`, unless it is named
+        name: name.map_or_else(|| field_names.join("_"), str::to_string),
+        fields: constraint_fields,
+    })
+}
+
+/// The `name:` argument of `@@id` or `@@unique`
+fn get_constraint_name(attribute: &Attribute) -> Option<&str> {
+    attribute.arguments.arguments.iter().find_map(|arg| {
+        if arg.name.as_ref().is_some_and(|name| name.name == "name") {
+            arg.value.as_string_value().map(|(value, _)| value)
+        } else {
+            None
+        }
+    })
+}
+
+fn variant_name(constraint: &Constraint) -> Ident {
+    let name = match constraint.fields.as_slice() {
+        [field] => to_pascal_case(&field.name.to_string()),
+        fields => fields
+            .iter()
+            .map(|field| to_pascal_case(&field.name.to_string()))
+            .collect(),
+    };
+    format_ident!("{}", name)
+}
+
+fn serde_rename(name: &str, serde: bool) -> Option<proc_macro2::TokenStream> {
+    serde.then(|| quote! { #[serde(rename = #name)] })
+}
+
+/// A field of a compound key, serialized with the Prisma field name. Enum variant fields have no visibility.
+fn key_field(
+    field: &StructField,
+    visibility: Option<Visibility>,
+    serde: bool,
+) -> proc_macro2::TokenStream {
+    let name = &field.name;
+    let type_name = field.rust_type();
+    let prisma_name = field.field.name();
+    let rename = if serde && *name != prisma_name {
+        Some(quote! { #[serde(rename = #prisma_name)] })
+    } else {
+        None
+    };
+
+    quote! {
+        #rename
+        #visibility #name: #type_name,
+    }
+}
@@ -0,0 +1,234 @@
+use prisma_rust_schema_core::{Generator, ImportOptions};
+
+fn generator(options: ImportOptions) -> Generator {
+    Generator::new(options).base_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+#[test]
+fn generate_string() {
+    let code = generator(ImportOptions {
+        derive: Some(vec!["Debug".to_string(), "Clone".to_string()]),
+        ..Default::default()
+    })
+    .add_schema("prisma/no-annotation-schema.prisma")
+    .generate_string()
+    .unwrap();
+
+    assert!(code.contains("#[derive(Debug, Clone)]"));
+    assert!(code.contains("pub struct User {"));
+    // Only the macro needs the schema files in its output
+    assert!(!code.contains("include_bytes!"));
+}
+
+#[test]
+fn track_files() {
+    let tokens = generator(ImportOptions::default())
+        .add_schema("prisma/schema.prisma")
+        .track_files(true)
+        .generate()
+        .unwrap()
+        .to_string();
+
+    assert!(tokens.contains("include_bytes !"));
+}
+
+#[test]
+fn skip_annotation() {
+    let code = generator(ImportOptions::default())
+        .add_schema("prisma/schema.prisma")
+        .generate_string()
+        .unwrap();
+
+    assert!(code.contains("pub struct User {"));
+    assert!(!code.contains("SkippedModel"));
+}
+
+#[test]
+fn missing_schema() {
+    let result = generator(ImportOptions::default())
+        .add_schema("prisma/missing.prisma")
+        .generate();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn views_have_no_inputs() {
+    let code = generator(ImportOptions {
+        create_input: Some(true),
+        update_input: Some(true),
+        ..Default::default()
+    })
+    .add_schema("prisma/views.prisma")
+    .generate_string()
+    .unwrap();
+
+    assert!(code.contains("pub struct ActiveAuthor {"));
+    assert!(code.contains("pub struct AuthorCreateInput {"));
+    assert!(!code.contains("ActiveAuthorCreateInput"));
+    assert!(!code.contains("ActiveAuthorUpdateInput"));
+    assert!(!code.contains("HiddenAuthor"));
+    assert!(code.contains("pub trait ReadOnly {}"));
+    assert!(code.contains("impl ReadOnly for ActiveAuthor {}"));
+    assert!(!code.contains("impl ReadOnly for Author {}"));
+}
+
+#[test]
+fn lenient_warnings() {
+    let source = "/// @prs.visiblity = private\nmodel User {\n  id Int @id\n}\n";
+    let generated = generator(ImportOptions {
+        strict: Some(false),
+        ..Default::default()
+    })
+    .add_source("lenient.prisma", source)
+    .generate_output()
+    .unwrap();
+
+    assert_eq!(generated.warnings.len(), 1);
+    assert!(generated.warnings[0].contains("lenient.prisma:1:"));
+    assert!(generated.to_source().unwrap().contains("pub struct User {"));
+
+    let result = generator(ImportOptions::default())
+        .add_source("lenient.prisma", source)
+        .generate();
+    assert!(result.is_err());
+}
+
+#[test]
+fn default_skipped_enum_value() {
+    let source = r#"
+enum Status {
+  ACTIVE
+  /// @prs.skip
+  LEGACY
+}
+
+model Account {
+  id     Int    @id
+  status Status @default(LEGACY)
+}
+"#;
+    let error = generator(ImportOptions {
+        derive: Some(vec!["Default".to_string()]),
+        ..Default::default()
+    })
+    .add_source("defaults.prisma", source)
+    .generate()
+    .unwrap_err()
+    .to_string();
+
+    assert!(error.contains("defaults.prisma:10:"), "{error}");
+    assert!(error.contains("`LEGACY`"), "{error}");
+}
+
+#[test]
+fn default_enum_without_values() {
+    let source = r#"
+enum Status {
+  /// @prs.skip
+  LEGACY
+}
+"#;
+    let error = generator(ImportOptions {
+        derive: Some(vec!["Default".to_string()]),
+        ..Default::default()
+    })
+    .add_source("defaults.prisma", source)
+    .generate()
+    .unwrap_err()
+    .to_string();
+
+    assert!(error.contains("defaults.prisma:2:"), "{error}");
+    assert!(error.contains("every value is skipped"), "{error}");
+}
+
+#[test]
+fn unique_key_derives() {
+    let source = r#"
+/// @prs.derive = Debug,Clone,PartialEq
+enum Role {
+  OWNER
+  MEMBER
+}
+
+model Member {
+  id    Int    @id @unique
+  orgId Int
+  role  Role
+
+  @@unique([orgId, role])
+}
+"#;
+    let code = generator(ImportOptions {
+        derive: Some(vec!["Debug".to_string()]),
+        unique_keys: Some(true),
+        ..Default::default()
+    })
+    .add_source("unique.prisma", source)
+    .generate_string()
+    .unwrap();
+
+    // The `Int` primary key implements every key trait
+    assert!(
+        code.contains("#[derive(Debug, Clone, PartialEq, Eq, Hash)]\npub struct MemberPrimaryKey(")
+    );
+    // `Role` does not implement `Eq` or `Hash`
+    assert!(code.contains("#[derive(Debug, Clone, PartialEq)]\npub enum MemberWhereUniqueInput {"));
+    // `@id @unique` is a single variant
+    assert_eq!(code.matches("Id(MemberPrimaryKey)").count(), 1);
+    assert!(!code.contains("Id(i32)"));
+}
+
+#[test]
+fn foreign_keys_of_id_without_newtype() {
+    let source = r#"
+model User {
+  /// @prs.type = uuid::Uuid
+  id    String @id
+  posts Post[]
+}
+
+model Team {
+  id    String @id
+  posts Post[]
+}
+
+model Post {
+  id       Int    @id
+  authorId String
+  author   User   @relation(fields: [authorId], references: [id])
+  teamId   String
+  team     Team   @relation(fields: [teamId], references: [id])
+}
+"#;
+    let code = generator(ImportOptions {
+        id_newtypes: Some(true),
+        patch: Some(vec![
+            syn::parse_str("struct Team { pub id: String }").unwrap(),
+        ]),
+        ..Default::default()
+    })
+    .add_source("newtypes.prisma", source)
+    .generate_string()
+    .unwrap();
+
+    // `User` and `Team` have no newtype, as their `@id` has `@prs.type` or a `patch`
+    assert!(code.contains("pub struct PostId("));
+    assert!(!code.contains("pub struct UserId("));
+    assert!(!code.contains("pub struct TeamId("));
+    assert!(code.contains("pub author_id: String,"));
+    assert!(code.contains("pub team_id: String,"));
+}
+
+#[test]
+fn duplicate_schema_paths() {
+    // The same file through `.` or `..` is only loaded once
+    let code = generator(ImportOptions::default())
+        .add_schema("prisma/schema.prisma")
+        .add_schema("prisma/../prisma/schema.prisma")
+        .add_schema("./prisma/schema.prisma")
+        .generate_string()
+        .unwrap();
+
+    assert_eq!(code.matches("pub struct User {").count(), 1);
+}
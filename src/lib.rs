@@ -1,24 +1,15 @@
 //! # Prisma Rust Schema
 //!
 //! A re-export of the Prisma TypeScript types in Rust.
+//!
+//! The types are generated by [`prisma_rust_schema_core`], this crate only parses the macro input.
 
-use annotation::{EnumAnnotation, EnumValueAnnotation, ModelAnnotation, TypeAnnotation};
-use code::{extract_docs, handle_derive, handle_fields};
-use psl::{
-    parse_schema,
-    schema_ast::ast::{Top, WithDocumentation, WithName},
-};
-use quote::{ToTokens, format_ident, quote};
+use prisma_rust_schema_core::{Generator, ImportOptions};
+use quote::ToTokens;
 use serde::Deserialize;
 use serde_tokenstream::{ParseWrapper, from_tokenstream};
 use syn::{ItemStruct, LitStr};
 
-use crate::transform::{get_enum_name, get_struct_name};
-
-mod annotation;
-mod code;
-mod transform;
-
 #[proc_macro]
 pub fn import_types(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     match handle_import(item) {
@@ -27,18 +18,67 @@ pub fn import_types(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
-#[derive(Deserialize, Debug)]
-struct ImportOptions {
+/// The macro input. See [`ImportOptions`] for the documentation of each option
+#[derive(Deserialize, Default)]
+struct MacroOptions {
     schema_paths: Vec<String>,
     derive: Option<Vec<ParseWrapper<syn::Path>>>,
     include: Option<Vec<String>>,
     prefix: Option<String>,
     patch: Option<Vec<ParseWrapper<ItemStruct>>>,
+    relations: Option<bool>,
+    create_input: Option<bool>,
+    update_input: Option<bool>,
+    where_input: Option<bool>,
+    field_enums: Option<bool>,
+    unique_keys: Option<bool>,
+    id_newtypes: Option<bool>,
+    sha256: Option<String>,
+    offline: Option<bool>,
+    strict: Option<bool>,
+    datetime: Option<String>,
+    object_id: Option<String>,
+    json: Option<String>,
+    decimal: Option<String>,
+    bytes: Option<String>,
+}
+
+impl From<MacroOptions> for ImportOptions {
+    fn from(options: MacroOptions) -> Self {
+        ImportOptions {
+            schema_paths: options.schema_paths,
+            derive: options.derive.map(|d| {
+                d.into_iter()
+                    .map(|i| i.to_token_stream().to_string())
+                    .collect()
+            }),
+            include: options.include,
+            prefix: options.prefix,
+            patch: options
+                .patch
+                .map(|p| p.into_iter().map(ParseWrapper::into_inner).collect()),
+            relations: options.relations,
+            create_input: options.create_input,
+            update_input: options.update_input,
+            where_input: options.where_input,
+            field_enums: options.field_enums,
+            unique_keys: options.unique_keys,
+            id_newtypes: options.id_newtypes,
+            sha256: options.sha256,
+            offline: options.offline,
+            strict: options.strict,
+            datetime: options.datetime,
+            object_id: options.object_id,
+            json: options.json,
+            decimal: options.decimal,
+            bytes: options.bytes,
+        }
+    }
 }
 
 fn handle_import(item: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenStream> {
     // Parse the input as a string literal `import_types("path.prisma")` or `import_types({ derive: [Path], include: [String]`
-    let import_options: ImportOptions = match from_tokenstream(&proc_macro2::TokenStream::from(
+    let macro_options: MacroOptions = match from_tokenstream(&proc_macro2::TokenStream::from(
         item.clone(),
     )) {
         Ok(opts) => opts,
@@ -56,338 +96,40 @@ fn handle_import(item: proc_macro::TokenStream) -> syn::Result<proc_macro::Token
                 }
             };
             let schema_path = lit_str.value();
-            ImportOptions {
+            MacroOptions {
                 schema_paths: vec![schema_path],
                 // TODO: Consider defaulting to SERDE
                 derive: None,
-                include: None,
-                prefix: None,
-                patch: None,
+                ..Default::default()
             }
         }
     };
 
-    let dir = std::env::var("CARGO_MANIFEST_DIR").map_or_else(
-        |_| std::env::current_dir().expect("current dir to be determined"),
-        |s| std::path::Path::new(&s).to_path_buf(),
-    );
-
-    // println!("{:#?}", import_options.patch);
+    let generated = Generator::new(macro_options.into())
+        .track_files(true)
+        .generate_output()?;
 
-    // Collect all schemas from the provided paths
-    let mut all_schemas = String::new();
-    for schema_path in &import_options.schema_paths {
-        let schema = match reqwest::blocking::get(schema_path) {
-            Ok(req) => req.text().map_err(|e| {
-                syn::Error::new_spanned(
-                    schema_path,
-                    format!(
-                        "Unable to extract text from provided url: {}",
-                        e.to_string()
-                    ),
-                )
-            })?,
-            Err(_e) => {
-                let path = dir.join(schema_path);
-                if !path.exists() {
-                    return Err(syn::Error::new_spanned(
-                        schema_path,
-                        format!("Schema file not found: {}", path.display()),
-                    ));
-                }
+    let tokens = generated.tokens;
+    let warnings = generated
+        .warnings
+        .iter()
+        .map(String::as_str)
+        .map(warning_tokens);
 
-                let schema = std::fs::read_to_string(&path)
-                    .map_err(|e| syn::Error::new_spanned(schema_path, e.to_string()))?;
-                schema
-            }
-        };
-        all_schemas.push_str(&schema);
-        all_schemas.push('\n');
+    Ok(quote::quote! {
+        #tokens
+        #(#warnings)*
     }
+    .into())
+}
 
-    let validated_schema = parse_schema(&all_schemas)
-        .map_err(|e| syn::Error::new_spanned(&all_schemas, e.to_string()))?;
-
-    let db = validated_schema.db;
-
-    // Remove single-file assertion, now supports multiple files
-    // assert_eq!(
-    //     db.files_count(),
-    //     1,
-    //     "This macro only supports single-file schemas"
-    // );
-
-    // Combine all AST tops from all schemas
-    let tops: Vec<_> = db
-        .into_iter_asts()
-        .flat_map(|ast| ast.tops.clone())
-        .collect();
-
-    // let mut output_token_stream = TokenStream::new();
-    let mut output_tokens = quote! {};
-
-    for top in &tops {
-        match top {
-            Top::CompositeType(composite_type) => {
-                let name = composite_type.name().to_string();
-                if import_options.include.is_some() {
-                    let include = import_options.include.as_ref().expect("UNREACHABLE");
-                    if !include.contains(&name) {
-                        continue;
-                    }
-                }
-
-                let TypeAnnotation {
-                    skip,
-                    rename,
-                    visibility,
-                    derive,
-                    type_,
-                } = match composite_type.documentation() {
-                    Some(d) => d.into(),
-                    None => TypeAnnotation::default(),
-                };
-                if skip {
-                    continue;
-                }
-
-                let derive = derive.or(import_options.derive.as_ref().map(|d| {
-                    d.into_iter()
-                        .map(|i| i.to_token_stream().to_string())
-                        .collect()
-                }));
-
-                if type_.is_some() {
-                    eprintln!(
-                        "`@prs.type` annotation is not supported for type name declarations."
-                    );
-                    eprintln!("Please use `@prs.rename` instead.");
-                    eprintln!("Skipping type name declaration for `{}`", name);
-                    continue;
-                }
-
-                let struct_name = get_struct_name(rename.unwrap_or(name), &import_options);
-
-                let documentation = extract_docs(composite_type.documentation().clone());
-                let fields = composite_type
-                    .iter_fields()
-                    .filter_map(|(_field_id, field)| handle_fields(&tops, &import_options, field));
-
-                let derive = handle_derive(derive);
-
-                let s = quote! {
-                    #documentation
-                    #derive
-                    #visibility struct #struct_name {
-                        #(#fields)*
-                    }
-                };
-
-                let s = if let Some(Some(patch)) = import_options
-                    .patch
-                    .as_ref()
-                    .map(|patches| patches.iter().find(|p| p.ident == struct_name))
-                {
-                    // Keep non-skipped fields, overwrite explicitly mentioned fields
-                    let item_struct = syn::parse2::<syn::ItemStruct>(s).expect("item struct");
-                    let patch_fields = &patch.fields;
-
-                    let fields = item_struct.fields.iter().map(|field| {
-                        if let Some(f) = patch_fields.iter().find(|f| f.ident == field.ident) {
-                            let f = quote! {
-                                #f,
-                            };
-                            f
-                        } else {
-                            let f = quote! {
-                                #field,
-                            };
-                            f
-                        }
-                    });
-
-                    quote! {
-                        #documentation
-                        #derive
-                        #visibility struct #struct_name {
-                            #(#fields)*
-                        }
-                    }
-                } else {
-                    s
-                };
-
-                output_tokens.extend(s);
-            }
-            Top::Enum(enum_type) => {
-                let name = enum_type.name().to_string();
-                if import_options.include.is_some() {
-                    let include = import_options.include.as_ref().expect("UNREACHABLE");
-                    if !include.contains(&name) {
-                        continue;
-                    }
-                }
-
-                let EnumAnnotation {
-                    skip,
-                    rename,
-                    visibility,
-                    derive,
-                } = match enum_type.documentation() {
-                    Some(d) => d.into(),
-                    None => EnumAnnotation::default(),
-                };
-                if skip {
-                    continue;
-                }
-
-                let derive = derive.or(import_options.derive.as_ref().map(|d| {
-                    d.into_iter()
-                        .filter_map(|i| {
-                            // Note: Currently, Default cannot be implemented for enum
-                            let d = i.to_token_stream().to_string();
-                            if d == "Default" {
-                                return None;
-                            }
-                            Some(d)
-                        })
-                        .collect()
-                }));
-
-                let enum_name = get_enum_name(rename.unwrap_or(name), &import_options);
-
-                let documentation = extract_docs(enum_type.documentation().clone());
-                let enum_values = enum_type.values.iter().filter_map(|enum_value| {
-                    let EnumValueAnnotation { skip, rename } = match enum_value.documentation() {
-                        Some(d) => d.into(),
-                        None => EnumValueAnnotation::default(),
-                    };
-
-                    if skip {
-                        return None;
-                    }
-
-                    let serde_rename = if let Some(_changed_name) = &rename {
-                        let original_name = enum_value.name();
-                        let s = quote! {
-                            #[serde(rename = #original_name)]
-                        };
-                        Some(s)
-                    } else {
-                        None
-                    };
-
-                    let name = match rename {
-                        Some(name) => name,
-                        None => enum_value.name().to_string(),
-                    };
-                    let name = format_ident!("{}", name);
-
-                    let documentation = extract_docs(enum_value.documentation().clone());
-
-                    return Some(quote! {
-                        #documentation
-                        #serde_rename
-                        #name,
-                    });
-                });
-
-                let derive = handle_derive(derive);
-
-                let s = quote! {
-                    #documentation
-                    #derive
-                    #visibility enum #enum_name {
-                        #(#enum_values)*
-                    }
-                };
-                output_tokens.extend(s);
-            }
-            Top::Model(model) => {
-                let name = model.name().to_string();
-                if import_options.include.is_some() {
-                    let include = import_options.include.as_ref().expect("UNREACHABLE");
-                    if !include.contains(&name) {
-                        continue;
-                    }
-                }
-
-                let ModelAnnotation {
-                    skip,
-                    rename,
-                    visibility,
-                    derive,
-                } = match model.documentation() {
-                    Some(d) => d.into(),
-                    None => ModelAnnotation::default(),
-                };
-                if skip {
-                    continue;
-                }
-
-                let derive = derive.or(import_options.derive.as_ref().map(|d| {
-                    d.into_iter()
-                        .map(|i| i.to_token_stream().to_string())
-                        .collect()
-                }));
-
-                let struct_name = get_struct_name(rename.unwrap_or(name), &import_options);
-                let documentation = extract_docs(model.documentation().clone());
-                let fields = model
-                    .iter_fields()
-                    .filter_map(|(_field_id, field)| handle_fields(&tops, &import_options, field));
-                let derive = handle_derive(derive);
-
-                let s = quote! {
-                    #documentation
-                    #derive
-                    #visibility struct #struct_name {
-                        #(#fields)*
-                    }
-                };
-
-                let s = if let Some(Some(patch)) = import_options
-                    .patch
-                    .as_ref()
-                    .map(|patches| patches.iter().find(|p| p.ident == struct_name))
-                {
-                    // Keep non-skipped fields, overwrite explicitly mentioned fields
-                    let item_struct = syn::parse2::<syn::ItemStruct>(s).expect("item struct");
-                    let patch_fields = &patch.fields;
-
-                    let fields = item_struct.fields.iter().map(|field| {
-                        if let Some(f) = patch_fields.iter().find(|f| f.ident == field.ident) {
-                            let f = quote! {
-                                #f,
-                            };
-                            f
-                        } else {
-                            let f = quote! {
-                                #field,
-                            };
-                            f
-                        }
-                    });
-
-                    quote! {
-                        #documentation
-                        #derive
-                        #visibility struct #struct_name {
-                            #(#fields)*
-                        }
-                    }
-                } else {
-                    s
-                };
-
-                output_tokens.extend(s);
-            }
-            _ => {
-                // Skip
-                continue;
-            }
-        }
+/// A compiler warning on stable, by using a deprecated constant
+fn warning_tokens(warning: &str) -> proc_macro2::TokenStream {
+    quote::quote! {
+        const _: () = {
+            #[deprecated(note = #warning)]
+            const WARNING: () = ();
+            WARNING
+        };
     }
-
-    Ok(output_tokens.into())
 }
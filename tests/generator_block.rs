@@ -0,0 +1,41 @@
+use prisma_rust_schema::import_types;
+
+// Every option from the `generator` block
+import_types!("./prisma/generator.prisma");
+
+// Explicit options override the `generator` block
+import_types!(
+    schema_paths = ["./prisma/generator.prisma"],
+    prefix = "Explicit",
+    derive = [Debug],
+);
+
+#[test]
+fn options_from_generator_block() {
+    let input = GenAccountCreateInput {
+        id: None,
+        email: "test@test.com".to_string(),
+        name: None,
+    };
+    let account = GenAccount {
+        id: 1,
+        email: input.email.clone(),
+        name: input.name.clone(),
+    };
+
+    assert_eq!(account.clone(), account);
+    assert_eq!(GenAccount::MODEL, "Account");
+}
+
+#[test]
+fn explicit_options_override_generator_block() {
+    let account = ExplicitAccount {
+        id: 1,
+        email: "test@test.com".to_string(),
+        name: None,
+    };
+
+    assert_eq!(format!("{:?}", account.id), "1");
+    // `create_input` is not overridden
+    let _ = ExplicitAccountCreateInput::from(account);
+}
@@ -0,0 +1,313 @@
+use prisma_rust_schema::import_types;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+
+import_types!(
+    schema_paths = ["./prisma/postgres.prisma"],
+    derive = [Deserialize, Serialize, Debug, Clone, PartialEq],
+    prefix = "C",
+    create_input = true,
+    update_input = true,
+    where_input = true,
+    unique_keys = true,
+);
+
+// Inputs without a serde derive have no `#[serde]` attributes
+import_types!(
+    schema_paths = ["./prisma/generator.prisma"],
+    derive = [Debug, Clone, PartialEq],
+    prefix = "Plain",
+    create_input = true,
+    update_input = true,
+    where_input = true,
+);
+
+#[test]
+fn generated_fields_are_optional() {
+    let input_json = json!({
+        "total": "12.34",
+        "discount": "0.5",
+        "pdf": [],
+    });
+
+    let input: CInvoiceCreateInput = serde_json::from_value(input_json).unwrap();
+    assert_eq!(input.id, None);
+    assert_eq!(input.issued_at, None);
+    assert_eq!(input.fee, None);
+    assert_eq!(input.logo, None);
+
+    let value = serde_json::to_value(&input).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "total": "12.34",
+            "discount": "0.5",
+            "pdf": [],
+        }),
+        "`None` fields are not serialized"
+    );
+}
+
+#[test]
+fn from_model() {
+    let invoice = CInvoice {
+        id: 1,
+        total: "12.34".to_string(),
+        fee: None,
+        discount: "0.5".to_string(),
+        pdf: vec![],
+        logo: None,
+        issued_at: "2025-01-01T00:00:00.000Z".to_string(),
+    };
+
+    let input = CInvoiceCreateInput::from(invoice);
+    assert_eq!(input.id, Some(1));
+    assert_eq!(
+        input.issued_at,
+        Some("2025-01-01T00:00:00.000Z".to_string())
+    );
+}
+
+#[test]
+fn into_model() {
+    let input: CTagCreateInput = serde_json::from_value(json!({ "name": "rust" })).unwrap();
+
+    let tag = CTag::from(input.clone());
+    assert_eq!(tag.name, input.name);
+    assert_eq!(tag.color, None);
+}
+
+#[test]
+fn update_input_fields_are_optional() {
+    let input: CInvoiceUpdateInput = serde_json::from_value(json!({ "total": "1.00" })).unwrap();
+    assert_eq!(input.total, Some("1.00".to_string()));
+    assert_eq!(input.discount, None);
+    assert_eq!(input.fee, None, "missing fields are left unchanged");
+
+    let input = CInvoiceUpdateInput {
+        discount: Some("0.5".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&input).unwrap(),
+        json!({ "discount": "0.5" })
+    );
+}
+
+#[test]
+fn update_input_nullable_fields() {
+    let input: CInvoiceUpdateInput = serde_json::from_value(json!({ "fee": null })).unwrap();
+    assert_eq!(input.fee, Some(None), "`null` sets the field to null");
+
+    let input: CInvoiceUpdateInput = serde_json::from_value(json!({ "fee": "2.00" })).unwrap();
+    assert_eq!(input.fee, Some(Some("2.00".to_string())));
+
+    let input = CTagUpdateInput { color: Some(None) };
+    assert_eq!(
+        serde_json::to_value(&input).unwrap(),
+        json!({ "color": null })
+    );
+}
+
+#[test]
+fn where_input_serializes_as_prisma_query() {
+    let filter = CLineItemWhereInput {
+        quantity: Some(CScalarFilter {
+            gte: Some(2),
+            ..Default::default()
+        }),
+        or: vec![
+            CLineItemWhereInput {
+                note: Some(CStringFilter {
+                    starts_with: Some("urgent".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            CLineItemWhereInput {
+                note: Some(CStringFilter {
+                    equals: Some(None),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        serde_json::to_value(&filter).unwrap(),
+        json!({
+            "quantity": { "gte": 2 },
+            "OR": [
+                { "note": { "startsWith": "urgent" } },
+                { "note": { "equals": null } },
+            ],
+        })
+    );
+}
+
+#[test]
+fn where_input_uses_prisma_field_names() {
+    let filter = CLineItemWhereInput {
+        due_on: Some(CScalarFilter {
+            lt: Some("2025-01-31".to_string()),
+            ..Default::default()
+        }),
+        not: vec![CLineItemWhereInput {
+            id: Some(CStringFilter {
+                in_: Some(vec!["a".to_string(), "b".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        serde_json::to_value(&filter).unwrap(),
+        json!({
+            "NOT": [{ "id": { "in": ["a", "b"] } }],
+            "dueOn": { "lt": "2025-01-31" },
+        })
+    );
+}
+
+#[test]
+fn where_input_filters_by_prisma_type() {
+    let filter = CSettingWhereInput {
+        enabled: Some(CBoolFilter {
+            equals: Some(true),
+            ..Default::default()
+        }),
+        status: Some(CEqualityFilter {
+            in_: Some(vec![CInvoiceStatus::PAID, CInvoiceStatus::Void]),
+            ..Default::default()
+        }),
+        retries: Some(CScalarFilter {
+            lte: Some(3),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    // Only numbers and dates have `lt`, `lte`, `gt` and `gte`
+    assert_eq!(
+        serde_json::to_value(&filter).unwrap(),
+        json!({
+            "enabled": { "equals": true },
+            "status": { "in": ["paid", "cancelled"] },
+            "retries": { "lte": 3 },
+        })
+    );
+}
+
+#[test]
+fn primary_keys() {
+    let tag = CTag {
+        name: "rust".to_string(),
+        color: None,
+    };
+    assert_eq!(tag.primary_key(), CTagPrimaryKey("rust".to_string()));
+
+    let membership = CMembership {
+        org_id: 1,
+        user_id: 2,
+        email: "a@example.com".to_string(),
+        slug: "a".to_string(),
+        region: "eu".to_string(),
+    };
+    let keys = HashSet::from([membership.primary_key()]);
+    assert!(keys.contains(&CMembershipPrimaryKey {
+        org_id: 1,
+        user_id: 2
+    }));
+}
+
+#[test]
+fn where_unique_input() {
+    let by_id = CTagWhereUniqueInput::from(CTagPrimaryKey("rust".to_string()));
+    assert_eq!(
+        serde_json::to_value(&by_id).unwrap(),
+        json!({ "name": "rust" })
+    );
+
+    let by_compound_id = CMembershipWhereUniqueInput::OrgIdUserId(CMembershipPrimaryKey {
+        org_id: 1,
+        user_id: 2,
+    });
+    assert_eq!(
+        serde_json::to_value(&by_compound_id).unwrap(),
+        json!({ "orgId_userId": { "orgId": 1, "userId": 2 } })
+    );
+
+    let by_email = CMembershipWhereUniqueInput::Email("a@example.com".to_string());
+    assert_eq!(
+        serde_json::to_value(&by_email).unwrap(),
+        json!({ "email": "a@example.com" })
+    );
+
+    let by_named_unique = CMembershipWhereUniqueInput::SlugRegion {
+        slug: "a".to_string(),
+        region: "eu".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_value(&by_named_unique).unwrap(),
+        json!({ "slugRegion": { "slug": "a", "region": "eu" } })
+    );
+}
+
+#[test]
+fn float_keys_are_not_hashable() {
+    // `Eq` and `Hash` are not derived, because `f64` implements neither
+    let key = CSamplePrimaryKey {
+        series: "a".to_string(),
+        at: 1.5,
+    };
+    assert_eq!(key.clone(), key);
+
+    let by_id = CSampleWhereUniqueInput::from(key);
+    assert_eq!(
+        serde_json::to_value(&by_id).unwrap(),
+        json!({ "series_at": { "series": "a", "at": 1.5 } })
+    );
+}
+
+#[test]
+fn create_input_without_serde() {
+    let input = PlainAccountCreateInput {
+        id: None,
+        email: "a@example.com".to_string(),
+        name: None,
+    };
+
+    let account = PlainAccount {
+        id: 1,
+        email: input.email.clone(),
+        name: None,
+    };
+    assert_eq!(PlainAccountCreateInput::from(account).id, Some(1));
+}
+
+#[test]
+fn update_input_without_serde() {
+    let input = PlainAccountUpdateInput {
+        name: Some(None),
+        ..Default::default()
+    };
+    assert_eq!(input.email, None);
+    assert_eq!(input.name, Some(None));
+}
+
+#[test]
+fn where_input_without_serde() {
+    let filter = PlainAccountWhereInput {
+        email: Some(PlainStringFilter {
+            ends_with: Some("@example.com".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(filter.clone(), filter);
+}
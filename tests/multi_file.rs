@@ -0,0 +1,55 @@
+use prisma_rust_schema::import_types;
+use serde::Deserialize;
+use serde_json::json;
+
+import_types!(
+    schema_paths = [
+        "./prisma/multi/base.prisma",
+        "./prisma/multi/books.prisma"
+    ],
+    derive = [Deserialize, Debug, PartialEq],
+);
+
+// A directory includes every `.prisma` file in it
+import_types!(
+    schema_paths = ["./prisma/multi"],
+    derive = [Deserialize, Debug, PartialEq],
+    prefix = "D",
+);
+
+// A file matched more than once is only loaded once
+import_types!(
+    schema_paths = ["./prisma/multi/*.prisma", "./prisma/multi/base.prisma"],
+    derive = [Deserialize, Debug, PartialEq],
+    prefix = "G",
+);
+
+#[test]
+fn relations_across_files() {
+    let book_json = json!({
+        "id": 1,
+        "title": "Sample Book",
+        "authorId": 2,
+    });
+
+    let book: Book = serde_json::from_value(book_json).unwrap();
+    assert_eq!(book.author_id, 2);
+    assert_eq!(Author::TABLE, "Author");
+}
+
+#[test]
+fn directories_and_globs() {
+    let book_json = json!({
+        "id": 1,
+        "title": "Sample Book",
+        "authorId": 2,
+    });
+
+    let book: DBook = serde_json::from_value(book_json.clone()).unwrap();
+    assert_eq!(book.title, "Sample Book".to_string());
+    assert_eq!(DAuthor::MODEL, "Author");
+
+    let book: GBook = serde_json::from_value(book_json).unwrap();
+    assert_eq!(book.title, "Sample Book".to_string());
+    assert_eq!(GAuthor::MODEL, "Author");
+}
@@ -0,0 +1,67 @@
+use bson::oid::ObjectId;
+use prisma_rust_schema::import_types;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+import_types!(
+    schema_paths = ["./prisma/no-annotation-schema.prisma"],
+    prefix = "I",
+    derive = [Deserialize, Serialize, Debug, Clone, PartialEq],
+    id_newtypes = true,
+);
+
+import_types!(
+    schema_paths = ["./prisma/postgres.prisma"],
+    prefix = "N",
+    derive = [Deserialize, Serialize, Debug, Clone, PartialEq],
+    id_newtypes = true,
+);
+
+#[test]
+fn foreign_keys_use_id_newtypes() {
+    let post_json = json!({
+        "_id": { "$oid": "507f1f77bcf86cd799439011" },
+        "title": "Sample Post",
+        "content": { "text": "Hello world", "images": [] },
+        "authorId": { "$oid": "507f1f77bcf86cd799439012" },
+        "badCaseId": { "$oid": "507f1f77bcf86cd799439013" },
+    });
+
+    let post: IPost = serde_json::from_value(post_json).unwrap();
+    let author_id: IUserId = post.author_id;
+    let bad_case_id: IBadCaseId = post.bad_case_id;
+    let id: IPostId = post.id;
+
+    assert_eq!(
+        author_id,
+        IUserId(ObjectId::parse_str("507f1f77bcf86cd799439012").unwrap())
+    );
+    assert_eq!(bad_case_id.to_string(), "507f1f77bcf86cd799439013");
+    assert_eq!(id.to_string(), "507f1f77bcf86cd799439011");
+}
+
+#[test]
+fn id_newtype_conversions() {
+    let object_id = ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap();
+
+    let user_id: IUserId = "507f1f77bcf86cd799439011".parse().unwrap();
+    assert_eq!(user_id, IUserId::from(object_id));
+    assert_eq!(ObjectId::from(user_id.clone()), object_id);
+    assert!("not an object id".parse::<IUserId>().is_err());
+
+    assert_eq!(
+        serde_json::to_value(&user_id).unwrap(),
+        serde_json::to_value(object_id).unwrap(),
+        "serializes like the wrapped type"
+    );
+}
+
+#[test]
+fn string_conversions_only_for_known_types() {
+    // `Vec<u8>` implements neither `Display` nor `FromStr`, so only the `From` conversions are generated
+    let hash = NBlobId::from(vec![1, 2, 3]);
+    assert_eq!(Vec::<u8>::from(hash), vec![1, 2, 3]);
+
+    let id: NInvoiceId = "7".parse().unwrap();
+    assert_eq!(id.to_string(), "7");
+}
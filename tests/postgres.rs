@@ -0,0 +1,170 @@
+use prisma_rust_schema::import_types;
+use serde::Deserialize;
+use serde_json::json;
+
+import_types!(
+    schema_paths = ["./prisma/postgres.prisma"],
+    derive = [Deserialize, Debug, PartialEq, Default],
+);
+
+import_types!(
+    schema_paths = ["./prisma/postgres.prisma"],
+    prefix = "Chrono",
+    derive = [Deserialize, Debug],
+    include = ["LineItem"],
+    datetime = "chrono",
+);
+
+#[test]
+fn decimal_fields() {
+    let invoice_json = json!({
+        "id": 1,
+        "total": "12.34",
+        "fee": null,
+        "discount": "0.5",
+        "pdf": [37, 80, 68, 70],
+        "logo": null,
+        "issuedAt": "2025-01-01T00:00:00.000Z"
+    });
+
+    let invoice: Invoice = serde_json::from_value(invoice_json).unwrap();
+    assert_eq!(invoice.id, 1i32);
+    assert_eq!(invoice.total, "12.34".to_string());
+    assert_eq!(invoice.fee, None);
+    assert_eq!(invoice.discount, "0.5".to_string());
+}
+
+#[test]
+fn bytes_fields() {
+    let invoice_json = json!({
+        "id": 1,
+        "total": "12.34",
+        "fee": "1.00",
+        "discount": "0.5",
+        "pdf": [37, 80, 68, 70],
+        "logo": [0, 255],
+        "issuedAt": "2025-01-01T00:00:00.000Z"
+    });
+
+    let invoice: Invoice = serde_json::from_value(invoice_json).unwrap();
+    assert_eq!(invoice.pdf, b"%PDF".to_vec());
+    assert_eq!(invoice.logo, Some(vec![0u8, 255u8]));
+}
+
+#[test]
+fn connector_defaults() {
+    let invoice_json = json!({
+        "id": 1,
+        "total": "12.34",
+        "fee": null,
+        "discount": "0.5",
+        "pdf": [],
+        "logo": null,
+        "issuedAt": "2025-01-01T00:00:00.000Z"
+    });
+
+    let invoice: Invoice = serde_json::from_value(invoice_json).unwrap();
+    // `bson` is a default feature, but only applies to MongoDB schemas
+    assert_eq!(invoice.issued_at, "2025-01-01T00:00:00.000Z".to_string());
+}
+
+#[test]
+fn native_types() {
+    let line_item_json = json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "quantity": 3,
+        "rate": 1.5,
+        "total": 9_007_199_254_740_993i64,
+        "note": null,
+        "dueOn": "2025-01-31T00:00:00.000Z",
+        "createdAt": "2025-01-01T00:00:00.000Z"
+    });
+
+    let line_item: LineItem = serde_json::from_value(line_item_json).unwrap();
+    // Without the `uuid` and `chrono` features, these fall back to `String`
+    assert_eq!(
+        line_item.id,
+        "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string()
+    );
+    assert_eq!(line_item.quantity, 3i16);
+    assert_eq!(line_item.rate, 1.5f32);
+    assert_eq!(line_item.total, 9_007_199_254_740_993i64);
+    assert_eq!(line_item.note, None::<String>);
+    assert_eq!(line_item.due_on, "2025-01-31T00:00:00.000Z".to_string());
+    assert_eq!(line_item.created_at, "2025-01-01T00:00:00.000Z".to_string());
+}
+
+#[test]
+fn native_date_types_deserialize_prisma_json() {
+    // Prisma Client returns `@db.Date` and `@db.Timestamptz` values as instants
+    let line_item_json = json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "quantity": 3,
+        "rate": 1.5,
+        "total": 1,
+        "note": null,
+        "dueOn": "2025-01-31T00:00:00.000Z",
+        "createdAt": "2025-01-01T12:30:00.000Z"
+    });
+
+    let line_item: ChronoLineItem = serde_json::from_value(line_item_json).unwrap();
+    let due_on: chrono::DateTime<chrono::Utc> = line_item.due_on;
+    assert_eq!(
+        due_on.date_naive(),
+        chrono::NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+    );
+    assert_eq!(
+        line_item.created_at.to_rfc3339(),
+        "2025-01-01T12:30:00+00:00"
+    );
+}
+
+#[test]
+fn enum_value_map() {
+    let status: InvoiceStatus = serde_json::from_value(json!("paid")).unwrap();
+    assert_eq!(status, InvoiceStatus::PAID);
+
+    let status: InvoiceStatus = serde_json::from_value(json!("cancelled")).unwrap();
+    assert_eq!(
+        status,
+        InvoiceStatus::Void,
+        "`@map` takes precedence over `@prs.rename`"
+    );
+
+    let status: InvoiceStatus = serde_json::from_value(json!("OVERDUE")).unwrap();
+    assert_eq!(status, InvoiceStatus::OVERDUE);
+}
+
+#[test]
+fn table_names() {
+    assert_eq!(Invoice::MODEL, "Invoice");
+    assert_eq!(Invoice::TABLE, "invoices");
+    assert_eq!(LineItem::MODEL, "LineItem");
+    assert_eq!(LineItem::TABLE, "LineItem", "falls back to the model name");
+    assert_eq!(InvoiceStatus::ENUM, "InvoiceStatus");
+    assert_eq!(InvoiceStatus::DB_NAME, "invoice_status");
+}
+
+#[test]
+fn default_impl() {
+    let setting = Setting::default();
+
+    assert_eq!(setting.id, 0);
+    assert!(setting.enabled);
+    assert_eq!(setting.retries, 3);
+    assert_eq!(setting.ratio, 1.0);
+    assert_eq!(setting.label, "draft".to_string());
+    assert_eq!(
+        setting.status,
+        InvoiceStatus::Void,
+        "uses the renamed variant"
+    );
+    assert_eq!(setting.nickname, Some("none".to_string()));
+    assert_eq!(setting.tags, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(
+        setting.limit, 42,
+        "`@prs.default` overrides the field default"
+    );
+
+    assert_eq!(InvoiceStatus::default(), InvoiceStatus::DRAFT);
+}
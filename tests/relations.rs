@@ -0,0 +1,129 @@
+use prisma_rust_schema::import_types;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+import_types!(
+    schema_paths = ["./prisma/no-annotation-schema.prisma"],
+    prefix = "R",
+    derive = [Deserialize, Serialize, Debug, PartialEq],
+    relations = true,
+);
+
+// `@prs.relation = include` and `@prs.type` on relation fields
+import_types!(
+    schema_paths = ["./prisma/relations.prisma"],
+    prefix = "Annotated",
+    derive = [Deserialize, Serialize, Debug, PartialEq],
+    create_input = true,
+);
+
+// Relation fields without a serde derive
+import_types!(
+    schema_paths = ["./prisma/relations.prisma"],
+    prefix = "Plain",
+    derive = [Debug, Clone, PartialEq],
+    relations = true,
+);
+
+#[test]
+fn relations_are_optional() {
+    let user_json = json!({
+        "_id": { "$oid": "507f1f77bcf86cd799439011" },
+        "email": "test@test.com",
+        "name": null,
+        "permission": "USER",
+        "createdAt": bson::DateTime::from_millis(1234567890),
+        "status": {},
+        "my_int": 1,
+        "my_float": 1.0,
+    });
+
+    let user: RUser = serde_json::from_value(user_json).unwrap();
+    assert_eq!(user.posts, None);
+    assert_eq!(user.bad_case, None);
+
+    let serialized = serde_json::to_value(&user).unwrap();
+    assert!(
+        serialized.get("posts").is_none(),
+        "unloaded relations are not serialized"
+    );
+}
+
+#[test]
+fn included_relations() {
+    let post_json = json!({
+        "_id": { "$oid": "507f1f77bcf86cd799439011" },
+        "title": "Sample Post",
+        "content": { "text": "Hello world", "images": [] },
+        "authorId": { "$oid": "507f1f77bcf86cd799439012" },
+        "badCaseId": { "$oid": "507f1f77bcf86cd799439013" },
+        "author": {
+            "_id": { "$oid": "507f1f77bcf86cd799439012" },
+            "email": "test@test.com",
+            "name": "John Doe",
+            "permission": "ADMIN",
+            "createdAt": bson::DateTime::from_millis(1234567890),
+            "status": {},
+            "my_int": 1,
+            "my_float": 1.0,
+            "posts": [],
+        },
+    });
+
+    let post: RPost = serde_json::from_value(post_json).unwrap();
+    let author = post.author.expect("author to be included");
+    assert_eq!(author.email, "test@test.com".to_string());
+    assert_eq!(author.posts, Some(vec![]));
+    assert_eq!(post.bad_case, None);
+}
+
+#[test]
+fn included_relation_annotation() {
+    let book: AnnotatedBook = serde_json::from_value(json!({
+        "id": 2,
+        "title": "Sequel",
+        "authorId": 1,
+        "reviews": ["Better than the first"],
+        "parentId": 1,
+        "parent": {
+            "id": 1,
+            "title": "First",
+            "authorId": 1,
+            "reviews": [],
+            "parentId": null,
+        },
+    }))
+    .unwrap();
+
+    let parent = book.parent.expect("parent to be included");
+    assert_eq!(parent.title, "First");
+    assert_eq!(parent.parent, None);
+    // `@prs.type` is used as written, without `Option`
+    assert_eq!(book.reviews, vec!["Better than the first".to_string()]);
+
+    let author: AnnotatedAuthor =
+        serde_json::from_value(json!({ "id": 1, "name": "Author" })).unwrap();
+    assert_eq!(author.books, None);
+}
+
+#[test]
+fn relations_without_serde() {
+    let author = PlainAuthor {
+        id: 1,
+        name: "Author".to_string(),
+        books: Some(vec![]),
+    };
+    let book = PlainBook {
+        id: 1,
+        title: "First".to_string(),
+        author_id: 1,
+        author: Some(Box::new(author.clone())),
+        reviews: vec![],
+        parent_id: None,
+        parent: None,
+        sequels: None,
+    };
+
+    assert_eq!(book.author.as_deref(), Some(&author));
+    assert_eq!(book.clone(), book);
+}
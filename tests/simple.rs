@@ -16,11 +16,13 @@ patch = [
     ]
 );
 
-impl Default for ADepE {
-    fn default() -> Self {
-        Self::A
-    }
-}
+import_types!(
+    schema_paths = ["./prisma/test.prisma"],
+    prefix = "S",
+    derive = [Deserialize, Debug, PartialEq],
+    object_id = "string",
+    field_enums = true,
+);
 
 #[test]
 fn simple() {
@@ -34,3 +36,39 @@ fn simple() {
 
     assert_eq!(t.defaulted, "".to_string());
 }
+
+#[test]
+fn default_impl() {
+    let t = ATest::default();
+
+    assert_eq!(t.defaulted, "".to_string());
+    assert_eq!(t.dep, ADep { e: ADepE::A }, "first enum value is the default");
+    assert_ne!(t.id, ATest::default().id, "`auto()` generates a new ObjectId");
+}
+
+#[test]
+fn object_id_option() {
+    let t: STest = serde_json::from_value(serde_json::json!({
+        "_id": "507f1f77bcf86cd799439011",
+        "defaulted": "",
+        "dep": { "e": "B" }
+    }))
+    .unwrap();
+
+    assert_eq!(t.id, "507f1f77bcf86cd799439011".to_string());
+    assert_eq!(t.dep.e, SDepE::B);
+}
+
+#[test]
+fn field_enums() {
+    assert_eq!(STestField::Id.as_str(), "_id", "uses the `@map` name");
+    assert_eq!(STestField::Id.rust_name(), "id");
+    assert_eq!(STestField::Defaulted.as_str(), "defaulted");
+    assert_eq!(
+        STestField::ALL,
+        &[STestField::Id, STestField::Defaulted, STestField::Dep]
+    );
+
+    assert_eq!(SDepField::E.as_str(), "e");
+    assert_eq!(SDepField::ALL, &[SDepField::E]);
+}
@@ -0,0 +1,53 @@
+use prisma_rust_schema::import_types;
+
+import_types!(
+    schema_paths = ["./prisma/views.prisma"],
+    prefix = "V",
+    derive = [Debug, Clone],
+    create_input = true,
+    update_input = true,
+    unique_keys = true,
+);
+
+#[test]
+fn views_are_read_only() {
+    assert!(VActiveAuthor::READ_ONLY);
+    assert!(!VAuthor::READ_ONLY);
+    assert_eq!(VActiveAuthor::MODEL, "ActiveAuthor");
+}
+
+fn assert_read_only<T: VReadOnly>() {}
+
+#[test]
+fn views_implement_read_only() {
+    assert_read_only::<VActiveAuthor>();
+}
+
+#[test]
+fn views_use_annotations() {
+    let author = VActiveAuthor {
+        id: 1,
+        name: "Author".to_string(),
+        posts: 2,
+    };
+
+    // `@prs.derive = Debug,Clone,PartialEq`
+    assert_eq!(author.clone(), author);
+    assert!(matches!(
+        VActiveAuthorWhereUniqueInput::Id(1),
+        VActiveAuthorWhereUniqueInput::Id(_)
+    ));
+}
+
+#[test]
+fn models_keep_inputs() {
+    let input = VAuthorCreateInput {
+        id: None,
+        name: "Author".to_string(),
+        posts: 0,
+    };
+    let update = VAuthorUpdateInput::default();
+
+    assert_eq!(input.name, "Author");
+    assert!(update.name.is_none());
+}